@@ -0,0 +1,237 @@
+use super::MemMap;
+use crate::{
+    ext::DFF,
+    module::{Clock, Extension, Module, Reset},
+    stmt::Stmt,
+    util::clog2,
+};
+
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Clone)]
+pub struct AHBLiteSlave {
+    name: Option<String>,
+    clk: Clock,
+    rst: Reset,
+    mem: MemMap,
+}
+
+impl AHBLiteSlave {
+    /// AHB-Lite slave with zero wait states and a two-cycle `hresp` error for unmapped addresses.
+    ///
+    /// `haddr` is a byte address: registers are selected by its word index
+    /// and byte lanes by its low bits and `hsize`.
+    pub fn new(
+        name: Option<&str>,
+        clk: impl Into<Clock>,
+        rst: impl Into<Reset>,
+        mem: MemMap,
+    ) -> Self {
+        Self {
+            name: name.map(|s| s.to_string()),
+            clk: clk.into(),
+            rst: rst.into(),
+            mem,
+        }
+    }
+
+    fn signal_names(&self) -> SignalNames {
+        let prefix = self
+            .name
+            .as_ref()
+            .map(|n| format!("{}_", n))
+            .unwrap_or_default();
+        SignalNames {
+            hsel: format!("{prefix}hsel"),
+            haddr: format!("{prefix}haddr"),
+            htrans: format!("{prefix}htrans"),
+            hwrite: format!("{prefix}hwrite"),
+            hsize: format!("{prefix}hsize"),
+            hburst: format!("{prefix}hburst"),
+            hprot: format!("{prefix}hprot"),
+            hwdata: format!("{prefix}hwdata"),
+            hready: format!("{prefix}hready"),
+            hreadyout: format!("{prefix}hreadyout"),
+            hresp: format!("{prefix}hresp"),
+            hrdata: format!("{prefix}hrdata"),
+            aaddr: format!("{prefix}aaddr"),
+            amiss: format!("{prefix}amiss"),
+            dphase: format!("{prefix}dphase"),
+            daddr: format!("{prefix}daddr"),
+            dwrite: format!("{prefix}dwrite"),
+            dsize: format!("{prefix}dsize"),
+            dlane: format!("{prefix}dlane"),
+            dmiss: format!("{prefix}dmiss"),
+            derr: format!("{prefix}derr"),
+            strb: format!("{prefix}strb"),
+            wmask: format!("{prefix}wmask"),
+            irq: format!("{prefix}irq"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct SignalNames {
+    hsel: String,
+    haddr: String,
+    htrans: String,
+    hwrite: String,
+    hsize: String,
+    hburst: String,
+    hprot: String,
+    hwdata: String,
+    hready: String,
+    hreadyout: String,
+    hresp: String,
+    hrdata: String,
+    aaddr: String,
+    amiss: String,
+    dphase: String,
+    daddr: String,
+    dwrite: String,
+    dsize: String,
+    dlane: String,
+    dmiss: String,
+    derr: String,
+    strb: String,
+    wmask: String,
+    irq: String,
+}
+
+impl Extension for AHBLiteSlave {
+    fn add(self, mut module: Module) -> Module {
+        let bus = self.signal_names();
+        let mem = &self.mem;
+        let bytes = mem.data_bit / 8;
+        let lane_bit = clog2(bytes).unwrap_or(0);
+
+        // Regs
+        module = module
+            .define_regs(mem)
+            .define_cdc(mem, &self.clk, &self.rst);
+
+        // IO Port
+        module = module
+            .input(&bus.hsel, 1)
+            .input(&bus.haddr, mem.addr_bit)
+            .input(&bus.htrans, 2)
+            .input(&bus.hwrite, 1)
+            .input(&bus.hsize, 3)
+            .input(&bus.hburst, 3)
+            .input(&bus.hprot, 4)
+            .input(&bus.hwdata, mem.data_bit)
+            .input(&bus.hready, 1)
+            .output(&bus.hreadyout, 1)
+            .output(&bus.hresp, 1)
+            .output(&bus.hrdata, mem.data_bit)
+            .define_irq(mem, &bus.irq);
+
+        // Address Phase
+        let word_bit = (mem.addr_bit - lane_bit).max(1);
+        module = module
+            .logic(&bus.aaddr, word_bit, 1)
+            .logic(&bus.amiss, 1, 1)
+            .always_comb(
+                Stmt::begin()
+                    .assign(&bus.aaddr, mem.word(&bus.haddr))
+                    .case(mem.reg_miss(&bus.aaddr, &bus.amiss))
+                    .end(),
+            );
+
+        // Data Phase
+        module = module
+            .logic(&bus.dphase, 1, 1)
+            .logic(&bus.daddr, word_bit, 1)
+            .logic(&bus.dwrite, 1, 1)
+            .logic(&bus.dsize, 3, 1)
+            .logic(&bus.dlane, lane_bit.max(1), 1)
+            .logic(&bus.dmiss, 1, 1)
+            .logic(&bus.derr, 1, 1)
+            .add(
+                DFF::new(
+                    Stmt::begin()
+                        .assign(&bus.dphase, "0")
+                        .assign(&bus.daddr, "0")
+                        .assign(&bus.dwrite, "0")
+                        .assign(&bus.dsize, "0")
+                        .assign(&bus.dlane, "0")
+                        .assign(&bus.dmiss, "0")
+                        .end(),
+                    Stmt::begin()
+                        .r#if(
+                            &bus.hready,
+                            Stmt::begin()
+                                .assign(&bus.dphase, format!("{} && {}[1]", bus.hsel, bus.htrans))
+                                .assign(&bus.daddr, &bus.aaddr)
+                                .assign(&bus.dwrite, &bus.hwrite)
+                                .assign(&bus.dsize, &bus.hsize)
+                                .assign(
+                                    &bus.dlane,
+                                    if lane_bit == 0 {
+                                        "0".to_string()
+                                    } else {
+                                        format!("{}[{}:0]", bus.haddr, lane_bit - 1)
+                                    },
+                                )
+                                .assign(&bus.dmiss, &bus.amiss)
+                                .end(),
+                        )
+                        .end(),
+                )
+                .on(&self.clk, &self.rst),
+            );
+
+        // Error Response
+        module = module
+            .add(
+                DFF::new(
+                    Stmt::assign(&bus.derr, "0"),
+                    Stmt::assign(
+                        &bus.derr,
+                        format!("{} && {} && !{}", bus.dphase, bus.dmiss, bus.derr),
+                    ),
+                )
+                .on(&self.clk, &self.rst),
+            )
+            .always_comb(
+                Stmt::begin()
+                    .assign(
+                        &bus.hreadyout,
+                        format!("!({} && {} && !{})", bus.dphase, bus.dmiss, bus.derr),
+                    )
+                    .assign(&bus.hresp, format!("{} && {}", bus.dphase, bus.dmiss))
+                    .end(),
+            );
+
+        // Write Logic
+        module = module.logic(&bus.strb, bytes, 1).always_comb(Stmt::assign(
+            &bus.strb,
+            format!("((1 << (1 << {})) - 1) << {}", bus.dsize, bus.dlane),
+        ));
+        module = module.define_wmask(&bus.strb, &bus.wmask, mem.data_bit);
+        module = module.add(
+            DFF::new(
+                mem.reg_init(),
+                mem.reg_update(Stmt::If(
+                    format!("{} && {} && !{}", bus.dphase, bus.dwrite, bus.dmiss),
+                    Box::new(
+                        Stmt::begin()
+                            .case(mem.reg_write(&bus.daddr, &bus.hwdata, Some(&bus.wmask)))
+                            .end(),
+                    ),
+                )),
+            )
+            .on(&self.clk, &self.rst),
+        );
+
+        // Read Logic
+        module = module.always_comb(
+            Stmt::begin()
+                .assign(&bus.hrdata, "0")
+                .case(mem.reg_read(&bus.daddr, &bus.hrdata))
+                .end(),
+        );
+
+        module
+    }
+}
@@ -0,0 +1,377 @@
+use super::MemMap;
+use crate::{
+    ext::DFF,
+    module::{Clock, Extension, Module, Reset},
+    stmt::Stmt,
+    util::clog2,
+};
+
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Clone)]
+pub struct APBSlave {
+    name: Option<String>,
+    clk: Clock,
+    rst: Reset,
+    mem: MemMap,
+    apb4: bool,
+    wait: usize,
+}
+
+impl APBSlave {
+    /// APB4 slave with zero wait states.
+    pub fn new(
+        name: Option<&str>,
+        clk: impl Into<Clock>,
+        rst: impl Into<Reset>,
+        mem: MemMap,
+    ) -> Self {
+        Self {
+            name: name.map(|s| s.to_string()),
+            clk: clk.into(),
+            rst: rst.into(),
+            mem,
+            apb4: true,
+            wait: 0,
+        }
+    }
+
+    /// Use APB3, without `pprot` and `pstrb`.
+    pub fn apb3(mut self) -> Self {
+        self.apb4 = false;
+        self
+    }
+
+    /// Insert `wait` wait states in every access phase by holding `pready` low.
+    pub fn wait(mut self, wait: usize) -> Self {
+        self.wait = wait;
+        self
+    }
+
+    fn signal_names(&self) -> SignalNames {
+        let prefix = self
+            .name
+            .as_ref()
+            .map(|n| format!("{}_", n))
+            .unwrap_or_default();
+        SignalNames {
+            paddr: format!("{prefix}paddr"),
+            psel: format!("{prefix}psel"),
+            penable: format!("{prefix}penable"),
+            pwrite: format!("{prefix}pwrite"),
+            pprot: format!("{prefix}pprot"),
+            pwdata: format!("{prefix}pwdata"),
+            pstrb: format!("{prefix}pstrb"),
+            prdata: format!("{prefix}prdata"),
+            pready: format!("{prefix}pready"),
+            pslverr: format!("{prefix}pslverr"),
+            wmask: format!("{prefix}wmask"),
+            wait: format!("{prefix}wait"),
+            irq: format!("{prefix}irq"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct SignalNames {
+    paddr: String,
+    psel: String,
+    penable: String,
+    pwrite: String,
+    pprot: String,
+    pwdata: String,
+    pstrb: String,
+    prdata: String,
+    pready: String,
+    pslverr: String,
+    wmask: String,
+    wait: String,
+    irq: String,
+}
+
+impl Extension for APBSlave {
+    fn add(self, mut module: Module) -> Module {
+        let bus = self.signal_names();
+        let mem = &self.mem;
+
+        // Regs
+        module = module
+            .define_regs(mem)
+            .define_cdc(mem, &self.clk, &self.rst);
+
+        // IO Port
+        module = module
+            .input(&bus.paddr, mem.addr_bit)
+            .input(&bus.psel, 1)
+            .input(&bus.penable, 1)
+            .input(&bus.pwrite, 1);
+        if self.apb4 {
+            module = module.input(&bus.pprot, 3);
+        }
+        module = module.input(&bus.pwdata, mem.data_bit);
+        if self.apb4 {
+            module = module.input(&bus.pstrb, mem.data_bit / 8);
+        }
+        module = module
+            .output(&bus.prdata, mem.data_bit)
+            .output(&bus.pready, 1)
+            .output(&bus.pslverr, 1)
+            .define_irq(mem, &bus.irq);
+
+        // Wait States
+        if self.wait == 0 {
+            module = module.always_comb(Stmt::assign(&bus.pready, "1"));
+        } else {
+            let width = clog2(self.wait + 1).unwrap_or(1).max(1);
+            module = module
+                .logic(&bus.wait, width, 1)
+                .always_comb(Stmt::assign(
+                    &bus.pready,
+                    format!("{} == {}", bus.wait, self.wait),
+                ))
+                .add(
+                    DFF::new(
+                        Stmt::assign(&bus.wait, "0"),
+                        Stmt::begin()
+                            .r#if(
+                                format!("{} && {} && !{}", bus.psel, bus.penable, bus.pready),
+                                Stmt::assign(&bus.wait, format!("{} + 1", bus.wait)),
+                            )
+                            .r#else(Stmt::assign(&bus.wait, "0"))
+                            .end(),
+                    )
+                    .on(&self.clk, &self.rst),
+                );
+        }
+
+        // Write Logic
+        let wmask = if self.apb4 {
+            module = module.define_wmask(&bus.pstrb, &bus.wmask, mem.data_bit);
+            Some(bus.wmask.as_str())
+        } else {
+            None
+        };
+        module = module.add(
+            DFF::new(
+                mem.reg_init(),
+                mem.reg_update(Stmt::If(
+                    format!(
+                        "{} && {} && {} && {}",
+                        bus.psel, bus.penable, bus.pwrite, bus.pready
+                    ),
+                    Box::new(
+                        Stmt::begin()
+                            .case(mem.reg_write(&mem.word(&bus.paddr), &bus.pwdata, wmask))
+                            .end(),
+                    ),
+                )),
+            )
+            .on(&self.clk, &self.rst),
+        );
+
+        // Read Logic / Error
+        module = module.always_comb(
+            Stmt::begin()
+                .assign(&bus.prdata, "0")
+                .case(mem.reg_read(&mem.word(&bus.paddr), &bus.prdata))
+                .case(mem.reg_miss(&mem.word(&bus.paddr), &bus.pslverr))
+                .end(),
+        );
+
+        module
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Clone)]
+pub struct APBMaster {
+    name: Option<String>,
+    clk: Clock,
+    rst: Reset,
+    addr_bit: usize,
+    data_bit: usize,
+    apb4: bool,
+}
+
+impl APBMaster {
+    /// APB4 master issuing one transfer at a time.
+    ///
+    /// Pulse `start` with `write`, `addr`, `wr_data` and `wr_strb` while `busy` is low.
+    /// `done` pulses at the end of the access phase, with `rd_data` and `error` valid until the next transfer.
+    pub fn new(
+        name: Option<&str>,
+        clk: impl Into<Clock>,
+        rst: impl Into<Reset>,
+        addr_bit: usize,
+        data_bit: usize,
+    ) -> Self {
+        Self {
+            name: name.map(|s| s.to_string()),
+            clk: clk.into(),
+            rst: rst.into(),
+            addr_bit,
+            data_bit,
+            apb4: true,
+        }
+    }
+
+    /// Use APB3, without `pprot` and `pstrb`.
+    pub fn apb3(mut self) -> Self {
+        self.apb4 = false;
+        self
+    }
+
+    fn signal_names(&self) -> MasterSignalNames {
+        let prefix = self
+            .name
+            .as_ref()
+            .map(|n| format!("{}_", n))
+            .unwrap_or_default();
+        MasterSignalNames {
+            paddr: format!("{prefix}paddr"),
+            psel: format!("{prefix}psel"),
+            penable: format!("{prefix}penable"),
+            pwrite: format!("{prefix}pwrite"),
+            pprot: format!("{prefix}pprot"),
+            pwdata: format!("{prefix}pwdata"),
+            pstrb: format!("{prefix}pstrb"),
+            prdata: format!("{prefix}prdata"),
+            pready: format!("{prefix}pready"),
+            pslverr: format!("{prefix}pslverr"),
+            start: format!("{prefix}start"),
+            write: format!("{prefix}write"),
+            addr: format!("{prefix}addr"),
+            wr_data: format!("{prefix}wr_data"),
+            wr_strb: format!("{prefix}wr_strb"),
+            busy: format!("{prefix}busy"),
+            done: format!("{prefix}done"),
+            rd_data: format!("{prefix}rd_data"),
+            error: format!("{prefix}error"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct MasterSignalNames {
+    paddr: String,
+    psel: String,
+    penable: String,
+    pwrite: String,
+    pprot: String,
+    pwdata: String,
+    pstrb: String,
+    prdata: String,
+    pready: String,
+    pslverr: String,
+    start: String,
+    write: String,
+    addr: String,
+    wr_data: String,
+    wr_strb: String,
+    busy: String,
+    done: String,
+    rd_data: String,
+    error: String,
+}
+
+impl Extension for APBMaster {
+    fn add(self, mut module: Module) -> Module {
+        let bus = self.signal_names();
+
+        // Front-end
+        module = module
+            .logic(&bus.start, 1, 1)
+            .logic(&bus.write, 1, 1)
+            .logic(&bus.addr, self.addr_bit, 1)
+            .logic(&bus.wr_data, self.data_bit, 1)
+            .logic(&bus.wr_strb, self.data_bit / 8, 1)
+            .logic(&bus.busy, 1, 1)
+            .logic(&bus.done, 1, 1)
+            .logic(&bus.rd_data, self.data_bit, 1)
+            .logic(&bus.error, 1, 1);
+
+        // IO Port
+        module = module
+            .output(&bus.paddr, self.addr_bit)
+            .output(&bus.psel, 1)
+            .output(&bus.penable, 1)
+            .output(&bus.pwrite, 1);
+        if self.apb4 {
+            module = module.output(&bus.pprot, 3);
+        }
+        module = module.output(&bus.pwdata, self.data_bit);
+        if self.apb4 {
+            module = module.output(&bus.pstrb, self.data_bit / 8);
+        }
+        module = module
+            .input(&bus.prdata, self.data_bit)
+            .input(&bus.pready, 1)
+            .input(&bus.pslverr, 1);
+
+        // Setup / Access Phase
+        let mut init = Stmt::begin()
+            .assign(&bus.paddr, "0")
+            .assign(&bus.psel, "0")
+            .assign(&bus.penable, "0")
+            .assign(&bus.pwrite, "0")
+            .assign(&bus.pwdata, "0");
+        let mut setup = Stmt::begin()
+            .assign(&bus.paddr, &bus.addr)
+            .assign(&bus.psel, "1")
+            .assign(&bus.pwrite, &bus.write)
+            .assign(&bus.pwdata, &bus.wr_data);
+        if self.apb4 {
+            module = module.always_comb(Stmt::assign(&bus.pprot, "0"));
+            init = init.assign(&bus.pstrb, "0");
+            setup = setup.assign(&bus.pstrb, format!("{} ? {} : 0", bus.write, bus.wr_strb));
+        }
+        module = module.always_comb(Stmt::assign(&bus.busy, &bus.psel)).add(
+            DFF::new(
+                init.end(),
+                Stmt::begin()
+                    .r#if(format!("{} && !{}", bus.start, bus.busy), setup.end())
+                    .elif(
+                        format!("{} && !{}", bus.psel, bus.penable),
+                        Stmt::assign(&bus.penable, "1"),
+                    )
+                    .elif(
+                        format!("{} && {} && {}", bus.psel, bus.penable, bus.pready),
+                        Stmt::begin()
+                            .assign(&bus.psel, "0")
+                            .assign(&bus.penable, "0")
+                            .end(),
+                    )
+                    .end(),
+            )
+            .on(&self.clk, &self.rst),
+        );
+
+        // Response
+        module = module.add(
+            DFF::new(
+                Stmt::begin()
+                    .assign(&bus.done, "0")
+                    .assign(&bus.rd_data, "0")
+                    .assign(&bus.error, "0")
+                    .end(),
+                Stmt::begin()
+                    .assign(
+                        &bus.done,
+                        format!("{} && {} && {}", bus.psel, bus.penable, bus.pready),
+                    )
+                    .r#if(
+                        format!("{} && {} && {}", bus.psel, bus.penable, bus.pready),
+                        Stmt::begin()
+                            .assign(&bus.rd_data, &bus.prdata)
+                            .assign(&bus.error, &bus.pslverr)
+                            .end(),
+                    )
+                    .end(),
+            )
+            .on(&self.clk, &self.rst),
+        );
+
+        module
+    }
+}
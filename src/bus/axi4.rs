@@ -0,0 +1,709 @@
+use super::MemMap;
+use crate::{
+    ext::{DFF, FIFO},
+    module::{Clock, Extension, Module, Reset},
+    stmt::{Block, Case, Stmt},
+    util::clog2,
+};
+
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Clone)]
+enum Backend {
+    Memory { depth: usize },
+    Regs(MemMap),
+}
+
+#[derive(Debug, Clone)]
+pub struct AXI4Slave {
+    name: Option<String>,
+    clk: Clock,
+    rst: Reset,
+    id_bit: usize,
+    addr_bit: usize,
+    data_bit: usize,
+    outstanding: usize,
+    backend: Backend,
+}
+
+impl AXI4Slave {
+    /// AXI4 slave in front of the registers of `mem`.
+    ///
+    /// `awaddr`/`araddr` are byte addresses, and register `i` is at byte `i * data_bit / 8`.
+    pub fn new(
+        name: Option<&str>,
+        clk: impl Into<Clock>,
+        rst: impl Into<Reset>,
+        mem: MemMap,
+    ) -> Self {
+        Self {
+            name: name.map(|s| s.to_string()),
+            clk: clk.into(),
+            rst: rst.into(),
+            id_bit: 4,
+            addr_bit: mem.addr_bit,
+            data_bit: mem.data_bit,
+            outstanding: 4,
+            backend: Backend::Regs(mem),
+        }
+    }
+
+    /// AXI4 slave in front of a `depth` words memory `<name>_mem`.
+    pub fn memory(
+        name: Option<&str>,
+        clk: impl Into<Clock>,
+        rst: impl Into<Reset>,
+        addr_bit: usize,
+        data_bit: usize,
+        depth: usize,
+    ) -> Self {
+        assert!(
+            depth * (data_bit / 8) <= 1 << addr_bit,
+            "Memory exceeds the address space"
+        );
+        Self {
+            name: name.map(|s| s.to_string()),
+            clk: clk.into(),
+            rst: rst.into(),
+            id_bit: 4,
+            addr_bit,
+            data_bit,
+            outstanding: 4,
+            backend: Backend::Memory { depth },
+        }
+    }
+
+    /// Width of `awid`/`bid`/`arid`/`rid`. Default is 4.
+    pub fn id_bit(mut self, id_bit: usize) -> Self {
+        self.id_bit = id_bit;
+        self
+    }
+
+    /// Bursts accepted per channel before `awready`/`arready` fall. Default is 4.
+    ///
+    /// Accepted addresses wait with their IDs in a queue, and bursts are served and answered in order.
+    pub fn outstanding(mut self, outstanding: usize) -> Self {
+        assert!(outstanding > 0, "AXI4 slave must accept at least one burst");
+        self.outstanding = outstanding;
+        self
+    }
+
+    fn prefix(&self) -> String {
+        self.name
+            .as_ref()
+            .map(|n| format!("{}_", n))
+            .unwrap_or_default()
+    }
+
+    /// Fields of an address queue entry, from the top bits down.
+    fn fields(&self) -> [(&'static str, usize); 5] {
+        [
+            ("id", self.id_bit),
+            ("addr", self.addr_bit),
+            ("len", 8),
+            ("size", 3),
+            ("burst", 2),
+        ]
+    }
+
+    /// Queue of the addresses accepted on channel `ch`, popped while `active` is low.
+    fn queue(&self, module: Module, p: &str, ch: &str, active: &str) -> Module {
+        let fields = self.fields();
+        let q = format!("{p}{ch}q");
+        let entry: Vec<String> = fields.iter().map(|(f, _)| format!("{p}{ch}{f}")).collect();
+        module
+            .add(
+                FIFO::new(
+                    &q,
+                    fields.iter().map(|(_, bit)| bit).sum(),
+                    self.outstanding,
+                )
+                .fwft()
+                .on(&self.clk, &self.rst),
+            )
+            .always_comb(
+                Stmt::begin()
+                    .assign(format!("{p}{ch}ready"), format!("!{q}_full"))
+                    .assign(format!("{q}_push"), format!("{p}{ch}valid && {p}{ch}ready"))
+                    .assign(format!("{q}_wdata"), format!("{{{}}}", entry.join(", ")))
+                    .assign(format!("{q}_pop"), format!("!{active} && !{q}_empty"))
+                    .end(),
+            )
+    }
+
+    /// Field `field` of the entry at the head of the queue of channel `ch`.
+    fn head(&self, p: &str, ch: &str, field: &str) -> String {
+        let fields = self.fields();
+        let i = fields.iter().position(|(f, _)| *f == field).unwrap();
+        let lo: usize = fields[i + 1..].iter().map(|(_, bit)| bit).sum();
+        format!("{p}{ch}q_rdata[{}:{lo}]", lo + fields[i].1 - 1)
+    }
+
+    /// Load the burst state of channel `ch` from the head of its queue.
+    fn load(&self, mut stmt: Block, p: &str, ch: &str) -> Block {
+        for (f, _) in self.fields() {
+            stmt = stmt.assign(format!("{p}{ch}{f}_q"), self.head(p, ch, f));
+        }
+        stmt
+    }
+
+    /// Address of the next beat of the burst on channel `ch` (FIXED / INCR / WRAP).
+    fn next_addr(module: Module, p: &str, ch: &str, addr_bit: usize) -> Module {
+        let (addr, len, size, burst) = (
+            format!("{p}{ch}addr_q"),
+            format!("{p}{ch}len_q"),
+            format!("{p}{ch}size_q"),
+            format!("{p}{ch}burst_q"),
+        );
+        let (incr, wrap, next) = (
+            format!("{p}{ch}incr"),
+            format!("{p}{ch}wrap"),
+            format!("{p}{ch}next"),
+        );
+        module
+            .logic(&incr, addr_bit, 1)
+            .logic(&wrap, addr_bit, 1)
+            .logic(&next, addr_bit, 1)
+            .always_comb(
+                Stmt::begin()
+                    .assign(
+                        &incr,
+                        format!("({addr} & ~((1 << {size}) - 1)) + (1 << {size})"),
+                    )
+                    .assign(&wrap, format!("(({len} + 1) << {size}) - 1"))
+                    .case(
+                        Case::new(&burst)
+                            .case("2'b00", Stmt::assign(&next, &addr))
+                            .case(
+                                "2'b10",
+                                Stmt::assign(
+                                    &next,
+                                    format!("({addr} & ~{wrap}) | ({incr} & {wrap})"),
+                                ),
+                            )
+                            .default(Stmt::assign(&next, &incr)),
+                    )
+                    .end(),
+            )
+    }
+}
+
+impl Extension for AXI4Slave {
+    fn add(self, mut module: Module) -> Module {
+        let p = self.prefix();
+        let (id_bit, addr_bit, data_bit) = (self.id_bit, self.addr_bit, self.data_bit);
+        let bytes = data_bit / 8;
+        let lane_bit = clog2(bytes).unwrap_or(0);
+
+        if let Backend::Regs(mem) = &self.backend {
+            module = module
+                .define_regs(mem)
+                .define_cdc(mem, &self.clk, &self.rst);
+        }
+
+        // IO Port
+        module = module
+            .input(format!("{p}awid"), id_bit)
+            .input(format!("{p}awaddr"), addr_bit)
+            .input(format!("{p}awlen"), 8)
+            .input(format!("{p}awsize"), 3)
+            .input(format!("{p}awburst"), 2)
+            .input(format!("{p}awvalid"), 1)
+            .output(format!("{p}awready"), 1)
+            .input(format!("{p}wdata"), data_bit)
+            .input(format!("{p}wstrb"), bytes)
+            .input(format!("{p}wlast"), 1)
+            .input(format!("{p}wvalid"), 1)
+            .output(format!("{p}wready"), 1)
+            .output(format!("{p}bid"), id_bit)
+            .output(format!("{p}bresp"), 2)
+            .output(format!("{p}bvalid"), 1)
+            .input(format!("{p}bready"), 1)
+            .input(format!("{p}arid"), id_bit)
+            .input(format!("{p}araddr"), addr_bit)
+            .input(format!("{p}arlen"), 8)
+            .input(format!("{p}arsize"), 3)
+            .input(format!("{p}arburst"), 2)
+            .input(format!("{p}arvalid"), 1)
+            .output(format!("{p}arready"), 1)
+            .output(format!("{p}rid"), id_bit)
+            .output(format!("{p}rdata"), data_bit)
+            .output(format!("{p}rresp"), 2)
+            .output(format!("{p}rlast"), 1)
+            .output(format!("{p}rvalid"), 1)
+            .input(format!("{p}rready"), 1);
+        if let Backend::Regs(mem) = &self.backend {
+            module = module.define_irq(mem, &format!("{p}irq"));
+        }
+
+        // Burst State
+        for ch in ["aw", "ar"] {
+            module = module
+                .logic(format!("{p}{ch}id_q"), id_bit, 1)
+                .logic(format!("{p}{ch}addr_q"), addr_bit, 1)
+                .logic(format!("{p}{ch}len_q"), 8, 1)
+                .logic(format!("{p}{ch}size_q"), 3, 1)
+                .logic(format!("{p}{ch}burst_q"), 2, 1);
+            module = Self::next_addr(module, &p, ch, addr_bit);
+        }
+
+        // Backend Access
+        let (waddr, raddr) = (format!("{p}awaddr_q"), format!("{p}araddr_q"));
+        let (wmiss, rmiss) = (format!("{p}wmiss"), format!("{p}rmiss"));
+        let (wword, rword) = (format!("{p}wword"), format!("{p}rword"));
+        let wmask = format!("{p}wmask");
+        let wbeat = format!("{p}wvalid && {p}wready");
+        module = module
+            .logic(&wmiss, 1, 1)
+            .logic(&rmiss, 1, 1)
+            .logic(format!("{p}rd"), data_bit, 1)
+            .define_wmask(&format!("{p}wstrb"), &wmask, data_bit);
+        match &self.backend {
+            Backend::Regs(mem) => {
+                let word_bit = (addr_bit - lane_bit).max(1);
+                module = module
+                    .logic(&wword, word_bit, 1)
+                    .logic(&rword, word_bit, 1)
+                    .always_comb(Stmt::assign(&wword, mem.word(&waddr)))
+                    .always_comb(Stmt::assign(&rword, mem.word(&raddr)))
+                    .always_comb(Stmt::Case(mem.reg_miss(&wword, &wmiss)))
+                    .always_comb(Stmt::Case(mem.reg_miss(&rword, &rmiss)))
+                    .always_comb(
+                        Stmt::begin()
+                            .assign(format!("{p}rd"), "0")
+                            .case(mem.reg_read(&rword, &format!("{p}rd")))
+                            .end(),
+                    )
+                    .add(
+                        DFF::new(
+                            mem.reg_init(),
+                            mem.reg_update(Stmt::If(
+                                format!("{wbeat} && !{wmiss}"),
+                                Box::new(
+                                    Stmt::begin()
+                                        .case(mem.reg_write(
+                                            &wword,
+                                            &format!("{p}wdata"),
+                                            Some(&wmask),
+                                        ))
+                                        .end(),
+                                ),
+                            )),
+                        )
+                        .on(&self.clk, &self.rst),
+                    );
+            }
+            Backend::Memory { depth } => {
+                let mem = format!("{p}mem");
+                let idx_bit = clog2(*depth).unwrap_or(1).max(1);
+                let idx = |addr: &str| format!("{addr}[{}:{lane_bit}]", lane_bit + idx_bit - 1);
+                let (widx, ridx) = (idx(&waddr), idx(&raddr));
+                let miss = |addr: &str| {
+                    if addr_bit - lane_bit > idx_bit || *depth < 1 << idx_bit {
+                        format!("{addr}[{}:{lane_bit}] >= {depth}", addr_bit - 1)
+                    } else {
+                        "0".to_string()
+                    }
+                };
+                module = module
+                    .logic(&mem, data_bit, *depth)
+                    .always_comb(Stmt::assign(&wmiss, miss(&waddr)))
+                    .always_comb(Stmt::assign(&rmiss, miss(&raddr)))
+                    .always_comb(Stmt::assign(
+                        format!("{p}rd"),
+                        format!("{rmiss} ? 0 : {mem}[{ridx}]"),
+                    ))
+                    .add(
+                        DFF::no_reset(Stmt::assign(
+                            format!("{mem}[{widx}]"),
+                            format!("({mem}[{widx}] & ~{wmask}) | ({p}wdata & {wmask})"),
+                        ))
+                        .enable(format!("{wbeat} && !{wmiss}"))
+                        .on(&self.clk, &self.rst),
+                    );
+            }
+        }
+
+        // Write Channel
+        module = module
+            .logic(format!("{p}wactive"), 1, 1)
+            .logic(format!("{p}werr"), 1, 1);
+        module = self
+            .queue(module, &p, "aw", &format!("{p}wactive"))
+            .always_comb(Stmt::assign(
+                format!("{p}wready"),
+                format!("{p}wactive && !{p}bvalid"),
+            ))
+            .add(
+                DFF::new(
+                    Stmt::begin()
+                        .assign(format!("{p}wactive"), "0")
+                        .assign(format!("{p}werr"), "0")
+                        .assign(format!("{p}awid_q"), "0")
+                        .assign(&waddr, "0")
+                        .assign(format!("{p}awlen_q"), "0")
+                        .assign(format!("{p}awsize_q"), "0")
+                        .assign(format!("{p}awburst_q"), "0")
+                        .assign(format!("{p}bid"), "0")
+                        .assign(format!("{p}bresp"), "0")
+                        .assign(format!("{p}bvalid"), "0")
+                        .end(),
+                    Stmt::begin()
+                        .r#if(
+                            format!("{p}awq_pop"),
+                            self.load(
+                                Stmt::begin()
+                                    .assign(format!("{p}wactive"), "1")
+                                    .assign(format!("{p}werr"), "0"),
+                                &p,
+                                "aw",
+                            )
+                            .end(),
+                        )
+                        .r#if(
+                            &wbeat,
+                            Stmt::begin()
+                                .assign(&waddr, format!("{p}awnext"))
+                                .assign(format!("{p}werr"), format!("{p}werr || {wmiss}"))
+                                .r#if(
+                                    format!("{p}wlast"),
+                                    Stmt::begin()
+                                        .assign(format!("{p}bid"), format!("{p}awid_q"))
+                                        .assign(
+                                            format!("{p}bresp"),
+                                            format!("{p}werr || {wmiss} ? 2'b10 : 2'b00"),
+                                        )
+                                        .assign(format!("{p}bvalid"), "1")
+                                        .end(),
+                                )
+                                .end(),
+                        )
+                        .r#if(
+                            format!("{p}bvalid && {p}bready"),
+                            Stmt::begin()
+                                .assign(format!("{p}bvalid"), "0")
+                                .assign(format!("{p}wactive"), "0")
+                                .end(),
+                        )
+                        .end(),
+                )
+                .on(&self.clk, &self.rst),
+            );
+
+        // Read Channel
+        let fetch = format!("{p}ractive && (!{p}rvalid || {p}rready)");
+        module = module
+            .logic(format!("{p}ractive"), 1, 1)
+            .logic(format!("{p}rcnt"), 8, 1);
+        module = self.queue(module, &p, "ar", &format!("{p}ractive")).add(
+            DFF::new(
+                Stmt::begin()
+                    .assign(format!("{p}ractive"), "0")
+                    .assign(format!("{p}rcnt"), "0")
+                    .assign(format!("{p}arid_q"), "0")
+                    .assign(&raddr, "0")
+                    .assign(format!("{p}arlen_q"), "0")
+                    .assign(format!("{p}arsize_q"), "0")
+                    .assign(format!("{p}arburst_q"), "0")
+                    .assign(format!("{p}rid"), "0")
+                    .assign(format!("{p}rdata"), "0")
+                    .assign(format!("{p}rresp"), "0")
+                    .assign(format!("{p}rlast"), "0")
+                    .assign(format!("{p}rvalid"), "0")
+                    .end(),
+                Stmt::begin()
+                    .r#if(
+                        format!("{p}rvalid && {p}rready"),
+                        Stmt::assign(format!("{p}rvalid"), "0"),
+                    )
+                    .r#if(
+                        format!("{p}arq_pop"),
+                        self.load(
+                            Stmt::begin()
+                                .assign(format!("{p}ractive"), "1")
+                                .assign(format!("{p}rcnt"), self.head(&p, "ar", "len")),
+                            &p,
+                            "ar",
+                        )
+                        .end(),
+                    )
+                    .elif(
+                        fetch,
+                        Stmt::begin()
+                            .assign(format!("{p}rid"), format!("{p}arid_q"))
+                            .assign(format!("{p}rdata"), format!("{p}rd"))
+                            .assign(format!("{p}rresp"), format!("{rmiss} ? 2'b10 : 2'b00"))
+                            .assign(format!("{p}rlast"), format!("{p}rcnt == 0"))
+                            .assign(format!("{p}rvalid"), "1")
+                            .assign(&raddr, format!("{p}arnext"))
+                            .assign(format!("{p}rcnt"), format!("{p}rcnt - 1"))
+                            .r#if(
+                                format!("{p}rcnt == 0"),
+                                Stmt::assign(format!("{p}ractive"), "0"),
+                            )
+                            .end(),
+                    )
+                    .end(),
+            )
+            .on(&self.clk, &self.rst),
+        );
+
+        module
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Clone)]
+pub struct AXI4Master {
+    name: Option<String>,
+    clk: Clock,
+    rst: Reset,
+    id_bit: usize,
+    addr_bit: usize,
+    data_bit: usize,
+}
+
+impl AXI4Master {
+    /// AXI4 master issuing one INCR burst of full-width beats at a time, with ID 0.
+    ///
+    /// Pulse `start` with `write`, `addr` and `len` (beats - 1) while `busy` is low.
+    /// Write beats are taken from `wr_data`/`wr_strb` when `wr_valid && wr_ready`.
+    /// Read beats appear on `rd_data` when `rd_valid`.
+    /// `done` pulses at the end of the burst, with `error` set if any beat failed.
+    pub fn new(
+        name: Option<&str>,
+        clk: impl Into<Clock>,
+        rst: impl Into<Reset>,
+        addr_bit: usize,
+        data_bit: usize,
+    ) -> Self {
+        Self {
+            name: name.map(|s| s.to_string()),
+            clk: clk.into(),
+            rst: rst.into(),
+            id_bit: 4,
+            addr_bit,
+            data_bit,
+        }
+    }
+
+    /// Width of `awid`/`bid`/`arid`/`rid`. Default is 4.
+    pub fn id_bit(mut self, id_bit: usize) -> Self {
+        self.id_bit = id_bit;
+        self
+    }
+}
+
+impl Extension for AXI4Master {
+    fn add(self, mut module: Module) -> Module {
+        let p = self
+            .name
+            .as_ref()
+            .map(|n| format!("{}_", n))
+            .unwrap_or_default();
+        let (id_bit, addr_bit, data_bit) = (self.id_bit, self.addr_bit, self.data_bit);
+        let bytes = data_bit / 8;
+        let size = clog2(bytes).unwrap_or(0);
+
+        // Front-end
+        module = module
+            .logic(format!("{p}start"), 1, 1)
+            .logic(format!("{p}write"), 1, 1)
+            .logic(format!("{p}addr"), addr_bit, 1)
+            .logic(format!("{p}len"), 8, 1)
+            .logic(format!("{p}busy"), 1, 1)
+            .logic(format!("{p}wr_data"), data_bit, 1)
+            .logic(format!("{p}wr_strb"), bytes, 1)
+            .logic(format!("{p}wr_valid"), 1, 1)
+            .logic(format!("{p}wr_ready"), 1, 1)
+            .logic(format!("{p}rd_data"), data_bit, 1)
+            .logic(format!("{p}rd_valid"), 1, 1)
+            .logic(format!("{p}done"), 1, 1)
+            .logic(format!("{p}error"), 1, 1);
+
+        // IO Port
+        module = module
+            .output(format!("{p}awid"), id_bit)
+            .output(format!("{p}awaddr"), addr_bit)
+            .output(format!("{p}awlen"), 8)
+            .output(format!("{p}awsize"), 3)
+            .output(format!("{p}awburst"), 2)
+            .output(format!("{p}awvalid"), 1)
+            .input(format!("{p}awready"), 1)
+            .output(format!("{p}wdata"), data_bit)
+            .output(format!("{p}wstrb"), bytes)
+            .output(format!("{p}wlast"), 1)
+            .output(format!("{p}wvalid"), 1)
+            .input(format!("{p}wready"), 1)
+            .input(format!("{p}bid"), id_bit)
+            .input(format!("{p}bresp"), 2)
+            .input(format!("{p}bvalid"), 1)
+            .output(format!("{p}bready"), 1)
+            .output(format!("{p}arid"), id_bit)
+            .output(format!("{p}araddr"), addr_bit)
+            .output(format!("{p}arlen"), 8)
+            .output(format!("{p}arsize"), 3)
+            .output(format!("{p}arburst"), 2)
+            .output(format!("{p}arvalid"), 1)
+            .input(format!("{p}arready"), 1)
+            .input(format!("{p}rid"), id_bit)
+            .input(format!("{p}rdata"), data_bit)
+            .input(format!("{p}rresp"), 2)
+            .input(format!("{p}rlast"), 1)
+            .input(format!("{p}rvalid"), 1)
+            .output(format!("{p}rready"), 1);
+
+        // Request
+        module = module
+            .always_comb(
+                Stmt::begin()
+                    .assign(format!("{p}awid"), "0")
+                    .assign(format!("{p}awsize"), size)
+                    .assign(format!("{p}awburst"), "2'b01")
+                    .assign(format!("{p}arid"), "0")
+                    .assign(format!("{p}arsize"), size)
+                    .assign(format!("{p}arburst"), "2'b01")
+                    .end(),
+            )
+            .add(
+                DFF::new(
+                    Stmt::begin()
+                        .assign(format!("{p}awaddr"), "0")
+                        .assign(format!("{p}awlen"), "0")
+                        .assign(format!("{p}awvalid"), "0")
+                        .assign(format!("{p}araddr"), "0")
+                        .assign(format!("{p}arlen"), "0")
+                        .assign(format!("{p}arvalid"), "0")
+                        .end(),
+                    Stmt::begin()
+                        .r#if(
+                            format!("{p}start && !{p}busy"),
+                            Stmt::begin()
+                                .r#if(
+                                    format!("{p}write"),
+                                    Stmt::begin()
+                                        .assign(format!("{p}awaddr"), format!("{p}addr"))
+                                        .assign(format!("{p}awlen"), format!("{p}len"))
+                                        .assign(format!("{p}awvalid"), "1")
+                                        .end(),
+                                )
+                                .r#else(
+                                    Stmt::begin()
+                                        .assign(format!("{p}araddr"), format!("{p}addr"))
+                                        .assign(format!("{p}arlen"), format!("{p}len"))
+                                        .assign(format!("{p}arvalid"), "1")
+                                        .end(),
+                                )
+                                .end(),
+                        )
+                        .r#if(
+                            format!("{p}awvalid && {p}awready"),
+                            Stmt::assign(format!("{p}awvalid"), "0"),
+                        )
+                        .r#if(
+                            format!("{p}arvalid && {p}arready"),
+                            Stmt::assign(format!("{p}arvalid"), "0"),
+                        )
+                        .end(),
+                )
+                .on(&self.clk, &self.rst),
+            );
+
+        // Write Data
+        module = module
+            .logic(format!("{p}wactive"), 1, 1)
+            .logic(format!("{p}wcnt"), 8, 1)
+            .always_comb(
+                Stmt::begin()
+                    .assign(format!("{p}wdata"), format!("{p}wr_data"))
+                    .assign(format!("{p}wstrb"), format!("{p}wr_strb"))
+                    .assign(format!("{p}wlast"), format!("{p}wcnt == 0"))
+                    .assign(format!("{p}wvalid"), format!("{p}wactive && {p}wr_valid"))
+                    .assign(format!("{p}wr_ready"), format!("{p}wactive && {p}wready"))
+                    .end(),
+            )
+            .add(
+                DFF::new(
+                    Stmt::begin()
+                        .assign(format!("{p}wactive"), "0")
+                        .assign(format!("{p}wcnt"), "0")
+                        .end(),
+                    Stmt::begin()
+                        .r#if(
+                            format!("{p}start && !{p}busy && {p}write"),
+                            Stmt::begin()
+                                .assign(format!("{p}wactive"), "1")
+                                .assign(format!("{p}wcnt"), format!("{p}len"))
+                                .end(),
+                        )
+                        .elif(
+                            format!("{p}wvalid && {p}wready"),
+                            Stmt::begin()
+                                .assign(format!("{p}wcnt"), format!("{p}wcnt - 1"))
+                                .r#if(
+                                    format!("{p}wlast"),
+                                    Stmt::assign(format!("{p}wactive"), "0"),
+                                )
+                                .end(),
+                        )
+                        .end(),
+                )
+                .on(&self.clk, &self.rst),
+            );
+
+        // Response
+        module = module
+            .always_comb(
+                Stmt::begin()
+                    .assign(format!("{p}bready"), format!("{p}busy"))
+                    .assign(format!("{p}rready"), format!("{p}busy"))
+                    .assign(format!("{p}rd_data"), format!("{p}rdata"))
+                    .assign(format!("{p}rd_valid"), format!("{p}rvalid && {p}rready"))
+                    .end(),
+            )
+            .add(
+                DFF::new(
+                    Stmt::begin()
+                        .assign(format!("{p}busy"), "0")
+                        .assign(format!("{p}done"), "0")
+                        .assign(format!("{p}error"), "0")
+                        .end(),
+                    Stmt::begin()
+                        .assign(format!("{p}done"), "0")
+                        .r#if(
+                            format!("{p}start && !{p}busy"),
+                            Stmt::begin()
+                                .assign(format!("{p}busy"), "1")
+                                .assign(format!("{p}error"), "0")
+                                .end(),
+                        )
+                        .r#if(
+                            format!("{p}bvalid && {p}bready"),
+                            Stmt::begin()
+                                .assign(format!("{p}busy"), "0")
+                                .assign(format!("{p}done"), "1")
+                                .assign(format!("{p}error"), format!("{p}bresp[1]"))
+                                .end(),
+                        )
+                        .r#if(
+                            format!("{p}rvalid && {p}rready"),
+                            Stmt::begin()
+                                .assign(format!("{p}error"), format!("{p}error || {p}rresp[1]"))
+                                .r#if(
+                                    format!("{p}rlast"),
+                                    Stmt::begin()
+                                        .assign(format!("{p}busy"), "0")
+                                        .assign(format!("{p}done"), "1")
+                                        .end(),
+                                )
+                                .end(),
+                        )
+                        .end(),
+                )
+                .on(&self.clk, &self.rst),
+            );
+
+        module
+    }
+}
@@ -1,9 +1,8 @@
 use super::MemMap;
 use crate::{
     ext::DFF,
-    module::{Extension, Module},
-    stmt::{Case, Stmt},
-    util::range,
+    module::{Clock, Extension, Module, Reset},
+    stmt::Stmt,
 };
 
 // ----------------------------------------------------------------------------
@@ -11,17 +10,22 @@ use crate::{
 #[derive(Debug, Clone)]
 pub struct AXILiteSlave {
     name: Option<String>,
-    clk: String,
-    rst: String,
+    clk: Clock,
+    rst: Reset,
     mem: MemMap,
 }
 
 impl AXILiteSlave {
-    pub fn new(name: Option<&str>, clk: impl ToString, rst: impl ToString, mem: MemMap) -> Self {
+    pub fn new(
+        name: Option<&str>,
+        clk: impl Into<Clock>,
+        rst: impl Into<Reset>,
+        mem: MemMap,
+    ) -> Self {
         Self {
             name: name.map(|s| s.to_string()),
-            clk: clk.to_string(),
-            rst: rst.to_string(),
+            clk: clk.into(),
+            rst: rst.into(),
             mem,
         }
     }
@@ -50,6 +54,7 @@ impl AXILiteSlave {
             rresp: format!("{prefix}rresp"),
             rvalid: format!("{prefix}rvalid"),
             rready: format!("{prefix}rready"),
+            irq: format!("{prefix}irq"),
         }
     }
 }
@@ -73,6 +78,7 @@ struct SignalNames {
     rresp: String,
     rvalid: String,
     rready: String,
+    irq: String,
 }
 
 impl Extension for AXILiteSlave {
@@ -81,7 +87,9 @@ impl Extension for AXILiteSlave {
         let mem = &self.mem;
 
         // Regs
-        module = module.define_regs(mem);
+        module = module
+            .define_regs(mem)
+            .define_cdc(mem, &self.clk, &self.rst);
 
         // IO Port
         module = module
@@ -101,96 +109,314 @@ impl Extension for AXILiteSlave {
             .output(&bus.rdata, mem.data_bit)
             .output(&bus.rresp, 2)
             .output(&bus.rvalid, 1)
-            .input(&bus.rready, 1);
+            .input(&bus.rready, 1)
+            .define_irq(mem, &bus.irq);
 
         // Write Logic
-        let init = {
-            let mut stmt = Stmt::begin();
-            for entry in &mem.map {
-                if let Some(name) = &entry.write {
-                    stmt = stmt.assign(&name, "0");
-                }
-            }
-            stmt.end()
-        };
-        let case = {
-            let mut cases = Case::new(&bus.awaddr);
-            for entry in &mem.map {
-                if let Some(name) = &entry.write {
-                    cases = cases.case(
-                        &format!("{}", entry.addr),
-                        Stmt::assign(name, &format!("{}{}", bus.wdata, range(entry.bit, 0))),
-                    );
-                }
-            }
-            cases.default(Stmt::empty())
-        };
-        module = module.add(DFF::sync(
-            init,
-            Stmt::begin()
-                .r#if(
-                    &format!("{} && {}", bus.wvalid, bus.awvalid),
-                    Stmt::begin().case(case).end(),
-                )
-                .end(),
-        ));
+        module = module.add(
+            DFF::new(
+                mem.reg_init(),
+                mem.reg_update(Stmt::If(
+                    format!("{} && {}", bus.wvalid, bus.awvalid),
+                    Box::new(
+                        Stmt::begin()
+                            .case(mem.reg_write(&mem.word(&bus.awaddr), &bus.wdata, None))
+                            .end(),
+                    ),
+                )),
+            )
+            .on(&self.clk, &self.rst),
+        );
 
         // Read Logic
-        let case = {
-            let mut cases = Case::new(&bus.araddr);
-            for entry in &mem.map {
-                if let Some(name) = &entry.read {
-                    cases = cases.case(
-                        &format!("{}", entry.addr),
-                        Stmt::assign(&format!("{}{}", bus.rdata, range(entry.bit, 0)), name),
-                    );
-                }
-            }
-            cases.default(Stmt::assign(&bus.rdata, "0"))
-        };
-        module = module.add(DFF::sync(
-            Stmt::assign(&bus.rdata, "0"),
-            Stmt::begin()
-                .r#if(&bus.arvalid, Stmt::begin().case(case).end())
-                .end(),
-        ));
+        module = module.add(
+            DFF::new(
+                Stmt::assign(&bus.rdata, "0"),
+                Stmt::begin()
+                    .r#if(
+                        &bus.arvalid,
+                        Stmt::begin()
+                            .case(mem.reg_read(&mem.word(&bus.araddr), &bus.rdata))
+                            .end(),
+                    )
+                    .end(),
+            )
+            .on(&self.clk, &self.rst),
+        );
 
         // AXI Lite Protocol
-        module = module.add(DFF::sync(
-            Stmt::begin()
-                .assign(&bus.awready, "0")
-                .assign(&bus.wready, "0")
-                .assign(&bus.bvalid, "0")
-                .assign(&bus.arready, "0")
-                .assign(&bus.rvalid, "0")
-                .assign(&bus.bresp, "0")
-                .assign(&bus.rresp, "0")
-                .end(),
-            Stmt::begin()
-                .assign(
-                    &bus.awready,
-                    &format!("{} && !{}", bus.awvalid, bus.awready),
-                )
-                .assign(&bus.wready, &format!("{} && !{}", bus.wvalid, bus.wready))
-                .assign(
-                    &bus.bvalid,
-                    &format!("{} && {} && !{}", bus.awready, bus.wready, bus.bvalid),
-                )
-                .assign(
-                    &bus.arready,
-                    &format!("{} && !{}", bus.arvalid, bus.arready),
-                )
-                .assign(&bus.rvalid, &format!("{} && !{}", bus.arvalid, bus.arready))
-                .r#if(
-                    &format!("{} && {}", bus.bvalid, bus.bready),
-                    Stmt::assign(&bus.bvalid, "0"),
-                )
-                .r#if(
-                    &format!("{} && {}", bus.rvalid, bus.rready),
-                    Stmt::assign(&bus.rvalid, "0"),
+        module = module.add(
+            DFF::new(
+                Stmt::begin()
+                    .assign(&bus.awready, "0")
+                    .assign(&bus.wready, "0")
+                    .assign(&bus.bvalid, "0")
+                    .assign(&bus.arready, "0")
+                    .assign(&bus.rvalid, "0")
+                    .assign(&bus.bresp, "0")
+                    .assign(&bus.rresp, "0")
+                    .end(),
+                Stmt::begin()
+                    .assign(
+                        &bus.awready,
+                        &format!("{} && !{}", bus.awvalid, bus.awready),
+                    )
+                    .assign(&bus.wready, &format!("{} && !{}", bus.wvalid, bus.wready))
+                    .assign(
+                        &bus.bvalid,
+                        &format!("{} && {} && !{}", bus.awready, bus.wready, bus.bvalid),
+                    )
+                    .assign(
+                        &bus.arready,
+                        &format!("{} && !{}", bus.arvalid, bus.arready),
+                    )
+                    .assign(&bus.rvalid, &format!("{} && !{}", bus.arvalid, bus.arready))
+                    .r#if(
+                        &format!("{} && {}", bus.bvalid, bus.bready),
+                        Stmt::assign(&bus.bvalid, "0"),
+                    )
+                    .r#if(
+                        &format!("{} && {}", bus.rvalid, bus.rready),
+                        Stmt::assign(&bus.rvalid, "0"),
+                    )
+                    .end(),
+            )
+            .on(&self.clk, &self.rst),
+        );
+
+        module
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Clone)]
+pub struct AXILiteMaster {
+    name: Option<String>,
+    clk: Clock,
+    rst: Reset,
+    addr_bit: usize,
+    data_bit: usize,
+}
+
+impl AXILiteMaster {
+    /// AXI Lite master issuing one access at a time.
+    ///
+    /// Pulse `start` with `write`, `addr`, `wr_data` and `wr_strb` while `busy` is low.
+    /// `done` pulses when the response arrives, with `rd_data` and `error` (SLVERR/DECERR) valid until the next access.
+    pub fn new(
+        name: Option<&str>,
+        clk: impl Into<Clock>,
+        rst: impl Into<Reset>,
+        addr_bit: usize,
+        data_bit: usize,
+    ) -> Self {
+        Self {
+            name: name.map(|s| s.to_string()),
+            clk: clk.into(),
+            rst: rst.into(),
+            addr_bit,
+            data_bit,
+        }
+    }
+
+    fn signal_names(&self) -> MasterSignalNames {
+        let prefix = self
+            .name
+            .as_ref()
+            .map(|n| format!("{}_", n))
+            .unwrap_or_default();
+        MasterSignalNames {
+            awaddr: format!("{prefix}awaddr"),
+            awvalid: format!("{prefix}awvalid"),
+            awready: format!("{prefix}awready"),
+            wdata: format!("{prefix}wdata"),
+            wstrb: format!("{prefix}wstrb"),
+            wvalid: format!("{prefix}wvalid"),
+            wready: format!("{prefix}wready"),
+            bresp: format!("{prefix}bresp"),
+            bvalid: format!("{prefix}bvalid"),
+            bready: format!("{prefix}bready"),
+            araddr: format!("{prefix}araddr"),
+            arvalid: format!("{prefix}arvalid"),
+            arready: format!("{prefix}arready"),
+            rdata: format!("{prefix}rdata"),
+            rresp: format!("{prefix}rresp"),
+            rvalid: format!("{prefix}rvalid"),
+            rready: format!("{prefix}rready"),
+            start: format!("{prefix}start"),
+            write: format!("{prefix}write"),
+            addr: format!("{prefix}addr"),
+            wr_data: format!("{prefix}wr_data"),
+            wr_strb: format!("{prefix}wr_strb"),
+            busy: format!("{prefix}busy"),
+            done: format!("{prefix}done"),
+            rd_data: format!("{prefix}rd_data"),
+            error: format!("{prefix}error"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct MasterSignalNames {
+    awaddr: String,
+    awvalid: String,
+    awready: String,
+    wdata: String,
+    wstrb: String,
+    wvalid: String,
+    wready: String,
+    bresp: String,
+    bvalid: String,
+    bready: String,
+    araddr: String,
+    arvalid: String,
+    arready: String,
+    rdata: String,
+    rresp: String,
+    rvalid: String,
+    rready: String,
+    start: String,
+    write: String,
+    addr: String,
+    wr_data: String,
+    wr_strb: String,
+    busy: String,
+    done: String,
+    rd_data: String,
+    error: String,
+}
+
+impl Extension for AXILiteMaster {
+    fn add(self, mut module: Module) -> Module {
+        let names = self.signal_names();
+
+        // Front-end
+        module = module
+            .logic(&names.start, 1, 1)
+            .logic(&names.write, 1, 1)
+            .logic(&names.addr, self.addr_bit, 1)
+            .logic(&names.wr_data, self.data_bit, 1)
+            .logic(&names.wr_strb, self.data_bit / 8, 1)
+            .logic(&names.busy, 1, 1)
+            .logic(&names.done, 1, 1)
+            .logic(&names.rd_data, self.data_bit, 1)
+            .logic(&names.error, 1, 1);
+
+        // IO Port
+        module = module
+            .output(&names.awaddr, self.addr_bit)
+            .output(&names.awvalid, 1)
+            .input(&names.awready, 1)
+            .output(&names.wdata, self.data_bit)
+            .output(&names.wstrb, self.data_bit / 8)
+            .output(&names.wvalid, 1)
+            .input(&names.wready, 1)
+            .input(&names.bresp, 2)
+            .input(&names.bvalid, 1)
+            .output(&names.bready, 1)
+            .output(&names.araddr, self.addr_bit)
+            .output(&names.arvalid, 1)
+            .input(&names.arready, 1)
+            .input(&names.rdata, self.data_bit)
+            .input(&names.rresp, 2)
+            .input(&names.rvalid, 1)
+            .output(&names.rready, 1);
+
+        // Request
+        module = module.add(
+            DFF::new(
+                Stmt::begin()
+                    .assign(&names.awaddr, "0")
+                    .assign(&names.awvalid, "0")
+                    .assign(&names.wdata, "0")
+                    .assign(&names.wstrb, "0")
+                    .assign(&names.wvalid, "0")
+                    .assign(&names.araddr, "0")
+                    .assign(&names.arvalid, "0")
+                    .end(),
+                Stmt::begin()
+                    .r#if(
+                        format!("{} && !{}", names.start, names.busy),
+                        Stmt::begin()
+                            .r#if(
+                                &names.write,
+                                Stmt::begin()
+                                    .assign(&names.awaddr, &names.addr)
+                                    .assign(&names.awvalid, "1")
+                                    .assign(&names.wdata, &names.wr_data)
+                                    .assign(&names.wstrb, &names.wr_strb)
+                                    .assign(&names.wvalid, "1")
+                                    .end(),
+                            )
+                            .r#else(
+                                Stmt::begin()
+                                    .assign(&names.araddr, &names.addr)
+                                    .assign(&names.arvalid, "1")
+                                    .end(),
+                            )
+                            .end(),
+                    )
+                    .r#if(
+                        format!("{} && {}", names.awvalid, names.awready),
+                        Stmt::assign(&names.awvalid, "0"),
+                    )
+                    .r#if(
+                        format!("{} && {}", names.wvalid, names.wready),
+                        Stmt::assign(&names.wvalid, "0"),
+                    )
+                    .r#if(
+                        format!("{} && {}", names.arvalid, names.arready),
+                        Stmt::assign(&names.arvalid, "0"),
+                    )
+                    .end(),
+            )
+            .on(&self.clk, &self.rst),
+        );
+
+        // Response
+        module = module
+            .always_comb(
+                Stmt::begin()
+                    .assign(&names.bready, &names.busy)
+                    .assign(&names.rready, &names.busy)
+                    .end(),
+            )
+            .add(
+                DFF::new(
+                    Stmt::begin()
+                        .assign(&names.busy, "0")
+                        .assign(&names.done, "0")
+                        .assign(&names.rd_data, "0")
+                        .assign(&names.error, "0")
+                        .end(),
+                    Stmt::begin()
+                        .assign(&names.done, "0")
+                        .r#if(
+                            format!("{} && !{}", names.start, names.busy),
+                            Stmt::assign(&names.busy, "1"),
+                        )
+                        .r#if(
+                            format!("{} && {}", names.bvalid, names.bready),
+                            Stmt::begin()
+                                .assign(&names.busy, "0")
+                                .assign(&names.done, "1")
+                                .assign(&names.error, format!("{}[1]", names.bresp))
+                                .end(),
+                        )
+                        .r#if(
+                            format!("{} && {}", names.rvalid, names.rready),
+                            Stmt::begin()
+                                .assign(&names.busy, "0")
+                                .assign(&names.done, "1")
+                                .assign(&names.rd_data, &names.rdata)
+                                .assign(&names.error, format!("{}[1]", names.rresp))
+                                .end(),
+                        )
+                        .end(),
                 )
-                .end(),
-        ));
+                .on(&self.clk, &self.rst),
+            );
 
         module
     }
@@ -0,0 +1,343 @@
+use crate::{
+    ext::DFF,
+    module::{Clock, Extension, Module, Reset},
+    stmt::{Block, Stmt},
+};
+
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Protocol {
+    Apb,
+    Wishbone,
+}
+
+#[derive(Debug, Clone)]
+pub struct Bridge {
+    up: Option<String>,
+    down: Option<String>,
+    clk: Clock,
+    rst: Reset,
+    protocol: Protocol,
+    addr_bit: usize,
+    data_bit: usize,
+}
+
+impl Bridge {
+    fn new(
+        protocol: Protocol,
+        up: Option<&str>,
+        down: Option<&str>,
+        clk: impl Into<Clock>,
+        rst: impl Into<Reset>,
+        addr_bit: usize,
+        data_bit: usize,
+    ) -> Self {
+        Self {
+            up: up.map(|s| s.to_string()),
+            down: down.map(|s| s.to_string()),
+            clk: clk.into(),
+            rst: rst.into(),
+            protocol,
+            addr_bit,
+            data_bit,
+        }
+    }
+
+    /// AXI Lite slave port `up` to APB4 master port `down`.
+    ///
+    /// One access is in flight at a time and writes win over reads.
+    /// `pslverr` is returned as SLVERR.
+    pub fn axi_lite_to_apb(
+        up: Option<&str>,
+        down: Option<&str>,
+        clk: impl Into<Clock>,
+        rst: impl Into<Reset>,
+        addr_bit: usize,
+        data_bit: usize,
+    ) -> Self {
+        Self::new(Protocol::Apb, up, down, clk, rst, addr_bit, data_bit)
+    }
+
+    /// AXI Lite slave port `up` to Wishbone classic master port `down`.
+    ///
+    /// One access is in flight at a time and writes win over reads.
+    /// `err` is returned as SLVERR.
+    pub fn axi_lite_to_wishbone(
+        up: Option<&str>,
+        down: Option<&str>,
+        clk: impl Into<Clock>,
+        rst: impl Into<Reset>,
+        addr_bit: usize,
+        data_bit: usize,
+    ) -> Self {
+        Self::new(Protocol::Wishbone, up, down, clk, rst, addr_bit, data_bit)
+    }
+
+    fn up_prefix(&self) -> String {
+        self.up
+            .as_ref()
+            .map(|n| format!("{}_", n))
+            .unwrap_or_default()
+    }
+
+    fn down_prefix(&self) -> String {
+        self.down
+            .as_ref()
+            .map(|n| format!("{}_", n))
+            .unwrap_or_default()
+    }
+}
+
+impl Extension for Bridge {
+    fn add(self, mut module: Module) -> Module {
+        let u = self.up_prefix();
+        let (addr_bit, data_bit) = (self.addr_bit, self.data_bit);
+
+        // AXI Lite Port
+        module = module
+            .input(format!("{u}awaddr"), addr_bit)
+            .input(format!("{u}awvalid"), 1)
+            .output(format!("{u}awready"), 1)
+            .input(format!("{u}wdata"), data_bit)
+            .input(format!("{u}wstrb"), data_bit / 8)
+            .input(format!("{u}wvalid"), 1)
+            .output(format!("{u}wready"), 1)
+            .output(format!("{u}bresp"), 2)
+            .output(format!("{u}bvalid"), 1)
+            .input(format!("{u}bready"), 1)
+            .input(format!("{u}araddr"), addr_bit)
+            .input(format!("{u}arvalid"), 1)
+            .output(format!("{u}arready"), 1)
+            .output(format!("{u}rdata"), data_bit)
+            .output(format!("{u}rresp"), 2)
+            .output(format!("{u}rvalid"), 1)
+            .input(format!("{u}rready"), 1);
+
+        // Accept
+        module = module.logic(format!("{u}busy"), 1, 1).always_comb(
+            Stmt::begin()
+                .assign(
+                    format!("{u}awready"),
+                    format!("!{u}busy && {u}awvalid && {u}wvalid"),
+                )
+                .assign(format!("{u}wready"), format!("{u}awready"))
+                .assign(
+                    format!("{u}arready"),
+                    format!("!{u}busy && {u}arvalid && !{u}awready"),
+                )
+                .end(),
+        );
+
+        match self.protocol {
+            Protocol::Apb => self.add_apb(module),
+            Protocol::Wishbone => self.add_wishbone(module),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl Bridge {
+    /// Response to the AXI Lite port when the downstream access completes.
+    fn respond(&self, block: Block, write: &str, rdata: &str, err: &str) -> Block {
+        let u = self.up_prefix();
+        block
+            .r#if(
+                write,
+                Stmt::begin()
+                    .assign(format!("{u}bvalid"), "1")
+                    .assign(format!("{u}bresp"), format!("{err} ? 2'b10 : 2'b00"))
+                    .end(),
+            )
+            .r#else(
+                Stmt::begin()
+                    .assign(format!("{u}rvalid"), "1")
+                    .assign(format!("{u}rdata"), rdata)
+                    .assign(format!("{u}rresp"), format!("{err} ? 2'b10 : 2'b00"))
+                    .end(),
+            )
+    }
+
+    fn up_init(&self, block: Block) -> Block {
+        let u = self.up_prefix();
+        block
+            .assign(format!("{u}busy"), "0")
+            .assign(format!("{u}bvalid"), "0")
+            .assign(format!("{u}bresp"), "0")
+            .assign(format!("{u}rvalid"), "0")
+            .assign(format!("{u}rdata"), "0")
+            .assign(format!("{u}rresp"), "0")
+    }
+
+    fn up_done(&self, block: Block) -> Block {
+        let u = self.up_prefix();
+        block
+            .r#if(
+                format!("{u}bvalid && {u}bready"),
+                Stmt::begin()
+                    .assign(format!("{u}bvalid"), "0")
+                    .assign(format!("{u}busy"), "0")
+                    .end(),
+            )
+            .r#if(
+                format!("{u}rvalid && {u}rready"),
+                Stmt::begin()
+                    .assign(format!("{u}rvalid"), "0")
+                    .assign(format!("{u}busy"), "0")
+                    .end(),
+            )
+    }
+
+    fn add_apb(self, mut module: Module) -> Module {
+        let u = self.up_prefix();
+        let d = self.down_prefix();
+        let (addr_bit, data_bit) = (self.addr_bit, self.data_bit);
+
+        // APB Port
+        module = module
+            .output(format!("{d}paddr"), addr_bit)
+            .output(format!("{d}psel"), 1)
+            .output(format!("{d}penable"), 1)
+            .output(format!("{d}pwrite"), 1)
+            .output(format!("{d}pprot"), 3)
+            .output(format!("{d}pwdata"), data_bit)
+            .output(format!("{d}pstrb"), data_bit / 8)
+            .input(format!("{d}prdata"), data_bit)
+            .input(format!("{d}pready"), 1)
+            .input(format!("{d}pslverr"), 1)
+            .always_comb(Stmt::assign(format!("{d}pprot"), "0"));
+
+        // Bridge
+        module = module.add(
+            DFF::new(
+                self.up_init(Stmt::begin())
+                    .assign(format!("{d}paddr"), "0")
+                    .assign(format!("{d}psel"), "0")
+                    .assign(format!("{d}penable"), "0")
+                    .assign(format!("{d}pwrite"), "0")
+                    .assign(format!("{d}pwdata"), "0")
+                    .assign(format!("{d}pstrb"), "0")
+                    .end(),
+                self.up_done(
+                    Stmt::begin()
+                        .r#if(
+                            format!("{u}awready"),
+                            Stmt::begin()
+                                .assign(format!("{u}busy"), "1")
+                                .assign(format!("{d}paddr"), format!("{u}awaddr"))
+                                .assign(format!("{d}psel"), "1")
+                                .assign(format!("{d}pwrite"), "1")
+                                .assign(format!("{d}pwdata"), format!("{u}wdata"))
+                                .assign(format!("{d}pstrb"), format!("{u}wstrb"))
+                                .end(),
+                        )
+                        .elif(
+                            format!("{u}arready"),
+                            Stmt::begin()
+                                .assign(format!("{u}busy"), "1")
+                                .assign(format!("{d}paddr"), format!("{u}araddr"))
+                                .assign(format!("{d}psel"), "1")
+                                .assign(format!("{d}pwrite"), "0")
+                                .assign(format!("{d}pstrb"), "0")
+                                .end(),
+                        )
+                        .elif(
+                            format!("{d}psel && !{d}penable"),
+                            Stmt::assign(format!("{d}penable"), "1"),
+                        )
+                        .elif(
+                            format!("{d}psel && {d}penable && {d}pready"),
+                            self.respond(
+                                Stmt::begin()
+                                    .assign(format!("{d}psel"), "0")
+                                    .assign(format!("{d}penable"), "0"),
+                                &format!("{d}pwrite"),
+                                &format!("{d}prdata"),
+                                &format!("{d}pslverr"),
+                            )
+                            .end(),
+                        ),
+                )
+                .end(),
+            )
+            .on(&self.clk, &self.rst),
+        );
+
+        module
+    }
+
+    fn add_wishbone(self, mut module: Module) -> Module {
+        let u = self.up_prefix();
+        let d = self.down_prefix();
+        let (addr_bit, data_bit) = (self.addr_bit, self.data_bit);
+
+        // Wishbone Port
+        module = module
+            .output(format!("{d}cyc"), 1)
+            .output(format!("{d}stb"), 1)
+            .output(format!("{d}we"), 1)
+            .output(format!("{d}sel"), data_bit / 8)
+            .output(format!("{d}adr"), addr_bit)
+            .output(format!("{d}dat_w"), data_bit)
+            .input(format!("{d}dat_r"), data_bit)
+            .input(format!("{d}ack"), 1)
+            .input(format!("{d}err"), 1);
+
+        // Bridge
+        module = module.add(
+            DFF::new(
+                self.up_init(Stmt::begin())
+                    .assign(format!("{d}cyc"), "0")
+                    .assign(format!("{d}stb"), "0")
+                    .assign(format!("{d}we"), "0")
+                    .assign(format!("{d}sel"), "0")
+                    .assign(format!("{d}adr"), "0")
+                    .assign(format!("{d}dat_w"), "0")
+                    .end(),
+                self.up_done(
+                    Stmt::begin()
+                        .r#if(
+                            format!("{u}awready"),
+                            Stmt::begin()
+                                .assign(format!("{u}busy"), "1")
+                                .assign(format!("{d}cyc"), "1")
+                                .assign(format!("{d}stb"), "1")
+                                .assign(format!("{d}we"), "1")
+                                .assign(format!("{d}sel"), format!("{u}wstrb"))
+                                .assign(format!("{d}adr"), format!("{u}awaddr"))
+                                .assign(format!("{d}dat_w"), format!("{u}wdata"))
+                                .end(),
+                        )
+                        .elif(
+                            format!("{u}arready"),
+                            Stmt::begin()
+                                .assign(format!("{u}busy"), "1")
+                                .assign(format!("{d}cyc"), "1")
+                                .assign(format!("{d}stb"), "1")
+                                .assign(format!("{d}we"), "0")
+                                .assign(format!("{d}sel"), "'1")
+                                .assign(format!("{d}adr"), format!("{u}araddr"))
+                                .end(),
+                        )
+                        .elif(
+                            format!("{d}cyc && ({d}ack || {d}err)"),
+                            self.respond(
+                                Stmt::begin()
+                                    .assign(format!("{d}cyc"), "0")
+                                    .assign(format!("{d}stb"), "0"),
+                                &format!("{d}we"),
+                                &format!("{d}dat_r"),
+                                &format!("{d}err"),
+                            )
+                            .end(),
+                        ),
+                )
+                .end(),
+            )
+            .on(&self.clk, &self.rst),
+        );
+
+        module
+    }
+}
@@ -0,0 +1,61 @@
+use super::MemMap;
+use crate::util::mask;
+
+impl MemMap {
+    /// C header with the address offsets, field masks and reset values of the registers.
+    /// Offsets are in bytes from the base of the bus slave, and `name` prefixes every macro,
+    /// e.g. `UART_CTRL_OFFSET`.
+    pub fn c_header(&self, name: &str) -> Vec<String> {
+        let prefix = name.to_uppercase();
+        let mut code: Vec<String> = vec![];
+        code.push(format!("#ifndef {prefix}_H"));
+        code.push(format!("#define {prefix}_H"));
+        for reg in &self.regs {
+            let reg_prefix = format!("{prefix}_{}", reg.name.to_uppercase());
+            code.push(String::new());
+            code.push(format!("/* {} : {} */", reg.name, reg.kind.access()));
+            if reg.len == 1 {
+                code.push(format!(
+                    "#define {reg_prefix}_OFFSET {:#x}",
+                    self.offset(reg.addr(0))
+                ));
+            } else {
+                code.push(format!(
+                    "#define {reg_prefix}_OFFSET(i) ({:#x} + (i) * {:#x})",
+                    self.offset(reg.addr(0)),
+                    self.offset(reg.stride)
+                ));
+                code.push(format!("#define {reg_prefix}_COUNT {}", reg.len));
+            }
+            code.push(format!(
+                "#define {reg_prefix}_MASK {}",
+                self.c_hex(mask(reg.bit))
+            ));
+            if reg.has_storage() {
+                code.push(format!(
+                    "#define {reg_prefix}_RESET {}",
+                    self.c_hex(reg.reset)
+                ));
+            }
+            for field in &reg.fields {
+                let field_prefix = format!("{reg_prefix}_{}", field.name.to_uppercase());
+                code.push(format!("#define {field_prefix}_SHIFT {}", field.lsb));
+                code.push(format!(
+                    "#define {field_prefix}_MASK {}",
+                    self.c_hex(mask(field.bit) << field.lsb)
+                ));
+            }
+        }
+        code.push(String::new());
+        code.push(format!("#endif /* {prefix}_H */"));
+        code
+    }
+
+    fn c_hex(&self, val: usize) -> String {
+        if self.data_bit <= 32 {
+            format!("{val:#x}u")
+        } else {
+            format!("{val:#x}ull")
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use super::{MemMap, Reg, RegKind};
+use crate::{
+    ext::{SyncHandshake, DFF},
+    module::{Clock, Module, Reset},
+    stmt::Stmt,
+    util::sel,
+};
+
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Clone)]
+pub(in crate::bus) struct Domain {
+    clk: Clock,
+    rst: Reset,
+}
+
+impl MemMap {
+    /// Consume the registers in the core clock domain `clk`/`rst` while the bus slave stays in the bus domain.
+    ///
+    /// - read-write / write-only registers get a copy `<name>_core` updated through a req/ack handshake.
+    /// - read-only registers are driven through `<name>_core` and handed over to the bus the same way.
+    /// - triggers become `<name>_trig_core` / `<name>_resp_core` through two-flop synchronizers.
+    ///
+    /// The handshakes are [`SyncHandshake`]s, and every synchronizer flop is marked `ASYNC_REG`.
+    ///
+    /// Hardware write ports and interrupt registers stay in the bus domain.
+    pub fn cdc(mut self, clk: impl Into<Clock>, rst: impl Into<Reset>) -> Self {
+        self.core = Some(Domain {
+            clk: clk.into(),
+            rst: rst.into(),
+        });
+        self
+    }
+}
+
+impl Module {
+    /// Synchronizers between the bus domain `clk`/`rst` and the core domain of `mem`.
+    pub(in crate::bus) fn define_cdc(mut self, mem: &MemMap, clk: &Clock, rst: &Reset) -> Self {
+        let Some(core) = &mem.core else {
+            return self;
+        };
+        let bus = Domain {
+            clk: clk.clone(),
+            rst: rst.clone(),
+        };
+        for reg in &mem.regs {
+            if !mem.irqs.is_empty() && ["int_status", "int_enable"].contains(&reg.name.as_str()) {
+                continue;
+            }
+            let name = &reg.name;
+            self = match reg.kind {
+                RegKind::ReadWrite | RegKind::WriteOnly => self
+                    .logic(format!("{name}_core"), reg.bit, reg.len)
+                    .handshake(reg, name, &format!("{name}_core"), &bus, core),
+                RegKind::ReadOnly if !reg.hw_write => self
+                    .logic(format!("{name}_core"), reg.bit, reg.len)
+                    .handshake(reg, &format!("{name}_core"), name, core, &bus),
+                RegKind::Trigger => self
+                    .logic(format!("{name}_resp_core"), 1, 1)
+                    .chain(
+                        &format!("{name}_trig"),
+                        &[format!("{name}_trig_core_s1"), format!("{name}_trig_core")],
+                        1,
+                        &core.clk,
+                        &core.rst,
+                    )
+                    .chain(
+                        &format!("{name}_resp_core"),
+                        &[format!("{name}_resp_s1"), format!("{name}_resp")],
+                        1,
+                        &bus.clk,
+                        &bus.rst,
+                    ),
+                _ => self,
+            };
+        }
+        self
+    }
+
+    /// Req/ack handshake carrying every element of `src` in domain `from` to `dst` in domain `to`.
+    ///
+    /// Each element goes through a [`SyncHandshake`] `<reg>_cdc` (`<reg>_cdc<i>` for arrays),
+    /// sent whenever `src` differs from the value last sent, and `dst` loads it on `valid`.
+    fn handshake(mut self, reg: &Reg, src: &str, dst: &str, from: &Domain, to: &Domain) -> Self {
+        for i in 0..reg.len {
+            let name = if reg.len == 1 {
+                format!("{}_cdc", reg.name)
+            } else {
+                format!("{}_cdc{i}", reg.name)
+            };
+            let [send, data, hold, output, valid] =
+                ["send", "data", "hold", "out", "valid"].map(|s| format!("{name}_{s}"));
+            let src = format!("{src}{}", sel(i, reg.len));
+            let dst = format!("{dst}{}", sel(i, reg.len));
+            self = self
+                .add(SyncHandshake::new(
+                    &name, reg.bit, &from.clk, &from.rst, &to.clk, &to.rst,
+                ))
+                .always_comb(
+                    Stmt::begin()
+                        .assign(&data, &src)
+                        .assign(&send, format!("{src} != {hold}"))
+                        .end(),
+                )
+                .add(
+                    DFF::new(
+                        Stmt::assign(&dst, reg.reset),
+                        Stmt::begin()
+                            .r#if(&valid, Stmt::assign(&dst, &output))
+                            .end(),
+                    )
+                    .on(&to.clk, &to.rst),
+                );
+        }
+        self
+    }
+}
@@ -1,19 +1,151 @@
-use super::{MemMap, Reg};
-use crate::module::Module;
+use super::{MemMap, RegKind, WriteOp};
+use crate::{
+    module::Module,
+    stmt::{Case, Stmt},
+    util::range,
+};
 
 impl Module {
     pub(in crate::bus) fn define_regs(mut self, mem: &MemMap) -> Self {
         for reg in &mem.regs {
-            self = match reg {
-                Reg::ReadWrite { name, bit, len } => self.logic(name, *bit, *len),
-                Reg::ReadOnly { name, bit, len } => self.logic(name, *bit, *len),
-                Reg::WriteOnly { name, bit, len } => self.logic(name, *bit, *len),
-                Reg::Trigger { name } => {
-                    self.logic(&format!("{name}_trig"), 1, 1)
-                        .logic(&format!("{name}_resp"), 1, 1)
+            self = match reg.kind {
+                RegKind::ReadWrite
+                | RegKind::ReadOnly
+                | RegKind::WriteOnly
+                | RegKind::IntStatus => self.logic(&reg.name, reg.bit, reg.len),
+                // The response comes out of the synchronizer from the core domain
+                RegKind::Trigger if mem.core.is_some() => {
+                    self.logic(format!("{}_trig", reg.name), 1, 1)
                 }
+                RegKind::Trigger => self.logic(format!("{}_trig", reg.name), 1, 1).logic(
+                    format!("{}_resp", reg.name),
+                    1,
+                    1,
+                ),
+                RegKind::IntTest => self,
             };
+            if reg.hw_write {
+                self = self.logic(format!("{}_hw_we", reg.name), 1, reg.len).logic(
+                    format!("{}_hw_d", reg.name),
+                    reg.bit,
+                    reg.len,
+                );
+            }
         }
         self
     }
+
+    /// Byte strobes expanded to a bit mask.
+    pub(in crate::bus) fn define_wmask(self, strb: &str, wmask: &str, data_bit: usize) -> Self {
+        let bytes: Vec<String> = (0..data_bit / 8)
+            .rev()
+            .map(|i| format!("{{8{{{strb}[{i}]}}}}"))
+            .collect();
+        self.logic(wmask, data_bit, 1)
+            .always_comb(Stmt::assign(wmask, format!("{{{}}}", bytes.join(", "))))
+    }
+
+    /// Interrupt request output, when the register map has interrupt sources.
+    pub(in crate::bus) fn define_irq(self, mem: &MemMap, irq: &str) -> Self {
+        if mem.irqs.is_empty() {
+            return self;
+        }
+        self.output(irq, 1)
+            .always_comb(Stmt::assign(irq, "|(int_status & int_enable)"))
+    }
+}
+
+impl MemMap {
+    /// Reset every register driven by the bus slave.
+    pub(in crate::bus) fn reg_init(&self) -> Stmt {
+        let mut vars: Vec<(&str, usize)> = vec![];
+        for entry in &self.map {
+            let var = entry.write.as_ref().or(entry.hw.as_ref().map(|hw| &hw.var));
+            if let Some(var) = var {
+                if vars.iter().all(|(v, _)| v != var) {
+                    vars.push((var, entry.reset));
+                }
+            }
+        }
+        let mut stmt = Stmt::begin();
+        for (var, reset) in vars {
+            stmt = stmt.assign(var, reset);
+        }
+        stmt.end()
+    }
+
+    /// Bus write to the register selected by `addr`.
+    /// With `wmask`, only the bits whose mask is set are written.
+    pub(in crate::bus) fn reg_write(&self, addr: &str, wdata: &str, wmask: Option<&str>) -> Case {
+        let mut cases = Case::new(addr);
+        for entry in &self.map {
+            if let Some(name) = &entry.write {
+                let bits = range(entry.bit, 0);
+                let data = match wmask {
+                    Some(wmask) => format!("({wdata}{bits} & {wmask}{bits})"),
+                    None => format!("{wdata}{bits}"),
+                };
+                let val = match (entry.op, wmask) {
+                    (WriteOp::Store, Some(wmask)) => format!("({name} & ~{wmask}{bits}) | {data}"),
+                    (WriteOp::Store, None) => data,
+                    (WriteOp::Clear, _) => format!("({name} & ~{data}) | {}", self.irq_src()),
+                    (WriteOp::Set, _) => format!("{name} | {data} | {}", self.irq_src()),
+                };
+                cases = cases.case(format!("{}", entry.addr), Stmt::assign(name, &val));
+            }
+        }
+        cases.default(Stmt::empty())
+    }
+
+    /// Flag `err` when `addr` selects no register.
+    pub(in crate::bus) fn reg_miss(&self, addr: &str, err: &str) -> Case {
+        let addrs: Vec<String> = self
+            .map
+            .iter()
+            .map(|entry| format!("{}", entry.addr))
+            .collect();
+        Case::new(addr)
+            .case(addrs.join(", "), Stmt::assign(err, "0"))
+            .default(Stmt::assign(err, "1"))
+    }
+
+    /// Bus read from the register selected by `addr`.
+    pub(in crate::bus) fn reg_read(&self, addr: &str, rdata: &str) -> Case {
+        let mut cases = Case::new(addr);
+        for entry in &self.map {
+            if let Some(name) = &entry.read {
+                cases = cases.case(
+                    format!("{}", entry.addr),
+                    Stmt::assign(format!("{}{}", rdata, range(entry.bit, 0)), name),
+                );
+            }
+        }
+        cases.default(Stmt::assign(rdata, "0"))
+    }
+
+    /// Register update around the bus `write`.
+    /// Interrupt sources are latched before it, and hardware writes come after it so that they win.
+    pub(in crate::bus) fn reg_update(&self, write: Stmt) -> Stmt {
+        let mut stmt = Stmt::begin();
+        if !self.irqs.is_empty() {
+            stmt = stmt.assign("int_status", format!("int_status | {}", self.irq_src()));
+        }
+        stmt = stmt.add(write);
+        for entry in &self.map {
+            if let Some(hw) = &entry.hw {
+                stmt = stmt.r#if(&hw.we, Stmt::assign(&hw.var, &hw.d));
+            }
+        }
+        stmt.end()
+    }
+
+    fn irq_src(&self) -> String {
+        match self.irqs.as_slice() {
+            [src] => src.clone(),
+            srcs => format!(
+                "{{{}}}",
+                srcs.iter().rev().cloned().collect::<Vec<_>>().join(", ")
+            ),
+        }
+    }
 }
@@ -0,0 +1,596 @@
+use super::MemMap;
+use crate::{
+    ext::DFF,
+    module::{Clock, Extension, Module, Reset},
+    stmt::{Case, Stmt},
+    util::clog2,
+};
+
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Protocol {
+    AxiLite,
+    Apb,
+    Wishbone,
+}
+
+#[derive(Debug, Clone)]
+pub struct Interconnect {
+    name: Option<String>,
+    clk: Clock,
+    rst: Reset,
+    protocol: Protocol,
+    addr_bit: usize,
+    data_bit: usize,
+    slaves: Vec<Port>,
+}
+
+#[derive(Debug, Clone)]
+struct Port {
+    name: String,
+    base: usize,
+    addr_bit: usize,
+}
+
+impl Interconnect {
+    fn new(
+        protocol: Protocol,
+        name: Option<&str>,
+        clk: impl Into<Clock>,
+        rst: impl Into<Reset>,
+        addr_bit: usize,
+        data_bit: usize,
+    ) -> Self {
+        Self {
+            name: name.map(|s| s.to_string()),
+            clk: clk.into(),
+            rst: rst.into(),
+            protocol,
+            addr_bit,
+            data_bit,
+            slaves: vec![],
+        }
+    }
+
+    /// AXI Lite 1-to-N interconnect with one outstanding write and one outstanding read.
+    /// Accesses to holes get a DECERR response.
+    pub fn axi_lite(
+        name: Option<&str>,
+        clk: impl Into<Clock>,
+        rst: impl Into<Reset>,
+        addr_bit: usize,
+        data_bit: usize,
+    ) -> Self {
+        Self::new(Protocol::AxiLite, name, clk, rst, addr_bit, data_bit)
+    }
+
+    /// APB4 decoder driving one `psel` per slave. Accesses to holes complete with `pslverr`.
+    pub fn apb(
+        name: Option<&str>,
+        clk: impl Into<Clock>,
+        rst: impl Into<Reset>,
+        addr_bit: usize,
+        data_bit: usize,
+    ) -> Self {
+        Self::new(Protocol::Apb, name, clk, rst, addr_bit, data_bit)
+    }
+
+    /// Wishbone classic decoder gating `cyc`/`stb` per slave. Accesses to holes get `err`.
+    pub fn wishbone(
+        name: Option<&str>,
+        clk: impl Into<Clock>,
+        rst: impl Into<Reset>,
+        addr_bit: usize,
+        data_bit: usize,
+    ) -> Self {
+        Self::new(Protocol::Wishbone, name, clk, rst, addr_bit, data_bit)
+    }
+
+    /// Map the slave `name` described by `mem` at `base`.
+    ///
+    /// The slave occupies `2^mem.addr_bit` addresses, so `base` must be aligned to that size.
+    /// Its port signals are prefixed with `name`, matching a slave generated with `Some(name)`.
+    pub fn slave(mut self, name: impl ToString, base: usize, mem: &MemMap) -> Self {
+        assert!(
+            mem.data_bit == self.data_bit,
+            "Data bit width of a slave must match the interconnect"
+        );
+        assert!(
+            mem.addr_bit <= self.addr_bit,
+            "Slave address space must fit in the interconnect"
+        );
+        let size = 1usize << mem.addr_bit;
+        assert!(
+            base.is_multiple_of(size),
+            "Base address must be aligned to the slave size"
+        );
+        assert!(
+            base + size <= 1 << self.addr_bit,
+            "Slave exceeds the address space"
+        );
+        assert!(
+            self.slaves
+                .iter()
+                .all(|s| base + size <= s.base || s.base + (1 << s.addr_bit) <= base),
+            "Slave overlaps another slave"
+        );
+        self.slaves.push(Port {
+            name: name.to_string(),
+            base,
+            addr_bit: mem.addr_bit,
+        });
+        self
+    }
+
+    fn prefix(&self) -> String {
+        self.name
+            .as_ref()
+            .map(|n| format!("{}_", n))
+            .unwrap_or_default()
+    }
+
+    fn sel_bit(&self) -> usize {
+        clog2(self.slaves.len() + 1).unwrap_or(1).max(1)
+    }
+
+    /// Decode `addr` into `dec`: the index of the slave, or the number of slaves for a hole.
+    fn decode(&self, addr: &str, dec: &str) -> Stmt {
+        let mut stmt = Stmt::begin();
+        for (i, slave) in self.slaves.iter().enumerate() {
+            let hit = if slave.addr_bit == self.addr_bit {
+                "1".to_string()
+            } else {
+                format!(
+                    "{addr}[{}:{}] == {}",
+                    self.addr_bit - 1,
+                    slave.addr_bit,
+                    slave.base >> slave.addr_bit
+                )
+            };
+            let assign = Stmt::assign(dec, i);
+            stmt = if i == 0 {
+                stmt.r#if(hit, assign)
+            } else {
+                stmt.elif(hit, assign)
+            };
+        }
+        let hole = Stmt::assign(dec, self.slaves.len());
+        if self.slaves.is_empty() {
+            hole
+        } else {
+            stmt.r#else(hole).end()
+        }
+    }
+
+    /// Slave-side address: the offset of `addr` inside the slave window.
+    fn offset(addr: &str, slave: &Port) -> String {
+        if slave.addr_bit == 0 {
+            "0".to_string()
+        } else {
+            format!("{addr}[{}:0]", slave.addr_bit - 1)
+        }
+    }
+}
+
+impl Extension for Interconnect {
+    fn add(self, module: Module) -> Module {
+        assert!(
+            !self.slaves.is_empty(),
+            "Interconnect needs at least one slave"
+        );
+        match self.protocol {
+            Protocol::AxiLite => self.add_axi_lite(module),
+            Protocol::Apb => self.add_apb(module),
+            Protocol::Wishbone => self.add_wishbone(module),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl Interconnect {
+    fn add_axi_lite(self, mut module: Module) -> Module {
+        let p = self.prefix();
+        let (addr_bit, data_bit) = (self.addr_bit, self.data_bit);
+        let sel_bit = self.sel_bit();
+        let hole = self.slaves.len();
+
+        // IO Port
+        module = module
+            .input(format!("{p}awaddr"), addr_bit)
+            .input(format!("{p}awvalid"), 1)
+            .output(format!("{p}awready"), 1)
+            .input(format!("{p}wdata"), data_bit)
+            .input(format!("{p}wstrb"), data_bit / 8)
+            .input(format!("{p}wvalid"), 1)
+            .output(format!("{p}wready"), 1)
+            .output(format!("{p}bresp"), 2)
+            .output(format!("{p}bvalid"), 1)
+            .input(format!("{p}bready"), 1)
+            .input(format!("{p}araddr"), addr_bit)
+            .input(format!("{p}arvalid"), 1)
+            .output(format!("{p}arready"), 1)
+            .output(format!("{p}rdata"), data_bit)
+            .output(format!("{p}rresp"), 2)
+            .output(format!("{p}rvalid"), 1)
+            .input(format!("{p}rready"), 1);
+        for s in &self.slaves {
+            let n = &s.name;
+            module = module
+                .output(format!("{n}_awaddr"), s.addr_bit)
+                .output(format!("{n}_awvalid"), 1)
+                .input(format!("{n}_awready"), 1)
+                .output(format!("{n}_wdata"), data_bit)
+                .output(format!("{n}_wstrb"), data_bit / 8)
+                .output(format!("{n}_wvalid"), 1)
+                .input(format!("{n}_wready"), 1)
+                .input(format!("{n}_bresp"), 2)
+                .input(format!("{n}_bvalid"), 1)
+                .output(format!("{n}_bready"), 1)
+                .output(format!("{n}_araddr"), s.addr_bit)
+                .output(format!("{n}_arvalid"), 1)
+                .input(format!("{n}_arready"), 1)
+                .input(format!("{n}_rdata"), data_bit)
+                .input(format!("{n}_rresp"), 2)
+                .input(format!("{n}_rvalid"), 1)
+                .output(format!("{n}_rready"), 1);
+        }
+
+        // Write Address / Data
+        module = module
+            .logic(format!("{p}wdec"), sel_bit, 1)
+            .logic(format!("{p}wbusy"), 1, 1)
+            .logic(format!("{p}wsel"), sel_bit, 1)
+            .logic(format!("{p}waddr"), addr_bit, 1)
+            .logic(format!("{p}wdata_q"), data_bit, 1)
+            .logic(format!("{p}wstrb_q"), data_bit / 8, 1)
+            .logic(format!("{p}awpend"), 1, 1)
+            .logic(format!("{p}wpend"), 1, 1)
+            .logic(format!("{p}awack"), 1, 1)
+            .logic(format!("{p}wack"), 1, 1)
+            .always_comb(self.decode(&format!("{p}awaddr"), &format!("{p}wdec")))
+            .always_comb(Stmt::assign(
+                format!("{p}awready"),
+                format!("!{p}wbusy && {p}awvalid && {p}wvalid"),
+            ))
+            .always_comb(Stmt::assign(format!("{p}wready"), format!("{p}awready")));
+        for (i, s) in self.slaves.iter().enumerate() {
+            let n = &s.name;
+            module = module.always_comb(
+                Stmt::begin()
+                    .assign(format!("{n}_awaddr"), Self::offset(&format!("{p}waddr"), s))
+                    .assign(
+                        format!("{n}_awvalid"),
+                        format!("{p}awpend && {p}wsel == {i}"),
+                    )
+                    .assign(format!("{n}_wdata"), format!("{p}wdata_q"))
+                    .assign(format!("{n}_wstrb"), format!("{p}wstrb_q"))
+                    .assign(format!("{n}_wvalid"), format!("{p}wpend && {p}wsel == {i}"))
+                    .assign(
+                        format!("{n}_bready"),
+                        format!("{p}wbusy && {p}wsel == {i} && {p}bready"),
+                    )
+                    .end(),
+            );
+        }
+
+        // Write Response
+        let mut resp = Case::new(format!("{p}wsel"));
+        for (i, s) in self.slaves.iter().enumerate() {
+            let n = &s.name;
+            resp = resp.case(
+                i,
+                Stmt::begin()
+                    .assign(format!("{p}awack"), format!("{n}_awready"))
+                    .assign(format!("{p}wack"), format!("{n}_wready"))
+                    .assign(format!("{p}bvalid"), format!("{p}wbusy && {n}_bvalid"))
+                    .assign(format!("{p}bresp"), format!("{n}_bresp"))
+                    .end(),
+            );
+        }
+        resp = resp.default(
+            Stmt::begin()
+                .assign(format!("{p}bvalid"), format!("{p}wbusy"))
+                .assign(format!("{p}bresp"), "2'b11")
+                .end(),
+        );
+        module = module.always_comb(
+            Stmt::begin()
+                .assign(format!("{p}awack"), "0")
+                .assign(format!("{p}wack"), "0")
+                .case(resp)
+                .end(),
+        );
+        module = module.add(
+            DFF::new(
+                Stmt::begin()
+                    .assign(format!("{p}wbusy"), "0")
+                    .assign(format!("{p}wsel"), "0")
+                    .assign(format!("{p}waddr"), "0")
+                    .assign(format!("{p}wdata_q"), "0")
+                    .assign(format!("{p}wstrb_q"), "0")
+                    .assign(format!("{p}awpend"), "0")
+                    .assign(format!("{p}wpend"), "0")
+                    .end(),
+                Stmt::begin()
+                    .r#if(
+                        format!("{p}awready"),
+                        Stmt::begin()
+                            .assign(format!("{p}wbusy"), "1")
+                            .assign(format!("{p}wsel"), format!("{p}wdec"))
+                            .assign(format!("{p}waddr"), format!("{p}awaddr"))
+                            .assign(format!("{p}wdata_q"), format!("{p}wdata"))
+                            .assign(format!("{p}wstrb_q"), format!("{p}wstrb"))
+                            .assign(format!("{p}awpend"), format!("{p}wdec != {hole}"))
+                            .assign(format!("{p}wpend"), format!("{p}wdec != {hole}"))
+                            .end(),
+                    )
+                    .r#if(
+                        format!("{p}awpend && {p}awack"),
+                        Stmt::assign(format!("{p}awpend"), "0"),
+                    )
+                    .r#if(
+                        format!("{p}wpend && {p}wack"),
+                        Stmt::assign(format!("{p}wpend"), "0"),
+                    )
+                    .r#if(
+                        format!("{p}bvalid && {p}bready"),
+                        Stmt::assign(format!("{p}wbusy"), "0"),
+                    )
+                    .end(),
+            )
+            .on(&self.clk, &self.rst),
+        );
+
+        // Read Address
+        module = module
+            .logic(format!("{p}rdec"), sel_bit, 1)
+            .logic(format!("{p}rbusy"), 1, 1)
+            .logic(format!("{p}rsel"), sel_bit, 1)
+            .logic(format!("{p}raddr"), addr_bit, 1)
+            .logic(format!("{p}arpend"), 1, 1)
+            .logic(format!("{p}arack"), 1, 1)
+            .always_comb(self.decode(&format!("{p}araddr"), &format!("{p}rdec")))
+            .always_comb(Stmt::assign(
+                format!("{p}arready"),
+                format!("!{p}rbusy && {p}arvalid"),
+            ));
+        for (i, s) in self.slaves.iter().enumerate() {
+            let n = &s.name;
+            module = module.always_comb(
+                Stmt::begin()
+                    .assign(format!("{n}_araddr"), Self::offset(&format!("{p}raddr"), s))
+                    .assign(
+                        format!("{n}_arvalid"),
+                        format!("{p}arpend && {p}rsel == {i}"),
+                    )
+                    .assign(
+                        format!("{n}_rready"),
+                        format!("{p}rbusy && {p}rsel == {i} && {p}rready"),
+                    )
+                    .end(),
+            );
+        }
+
+        // Read Data
+        let mut resp = Case::new(format!("{p}rsel"));
+        for (i, s) in self.slaves.iter().enumerate() {
+            let n = &s.name;
+            resp = resp.case(
+                i,
+                Stmt::begin()
+                    .assign(format!("{p}arack"), format!("{n}_arready"))
+                    .assign(format!("{p}rvalid"), format!("{p}rbusy && {n}_rvalid"))
+                    .assign(format!("{p}rdata"), format!("{n}_rdata"))
+                    .assign(format!("{p}rresp"), format!("{n}_rresp"))
+                    .end(),
+            );
+        }
+        resp = resp.default(
+            Stmt::begin()
+                .assign(format!("{p}rvalid"), format!("{p}rbusy"))
+                .assign(format!("{p}rdata"), "0")
+                .assign(format!("{p}rresp"), "2'b11")
+                .end(),
+        );
+        module = module.always_comb(
+            Stmt::begin()
+                .assign(format!("{p}arack"), "0")
+                .case(resp)
+                .end(),
+        );
+        module = module.add(
+            DFF::new(
+                Stmt::begin()
+                    .assign(format!("{p}rbusy"), "0")
+                    .assign(format!("{p}rsel"), "0")
+                    .assign(format!("{p}raddr"), "0")
+                    .assign(format!("{p}arpend"), "0")
+                    .end(),
+                Stmt::begin()
+                    .r#if(
+                        format!("{p}arready"),
+                        Stmt::begin()
+                            .assign(format!("{p}rbusy"), "1")
+                            .assign(format!("{p}rsel"), format!("{p}rdec"))
+                            .assign(format!("{p}raddr"), format!("{p}araddr"))
+                            .assign(format!("{p}arpend"), format!("{p}rdec != {hole}"))
+                            .end(),
+                    )
+                    .r#if(
+                        format!("{p}arpend && {p}arack"),
+                        Stmt::assign(format!("{p}arpend"), "0"),
+                    )
+                    .r#if(
+                        format!("{p}rvalid && {p}rready"),
+                        Stmt::assign(format!("{p}rbusy"), "0"),
+                    )
+                    .end(),
+            )
+            .on(&self.clk, &self.rst),
+        );
+
+        module
+    }
+
+    fn add_apb(self, mut module: Module) -> Module {
+        let p = self.prefix();
+        let (addr_bit, data_bit) = (self.addr_bit, self.data_bit);
+
+        // IO Port
+        module = module
+            .input(format!("{p}paddr"), addr_bit)
+            .input(format!("{p}psel"), 1)
+            .input(format!("{p}penable"), 1)
+            .input(format!("{p}pwrite"), 1)
+            .input(format!("{p}pprot"), 3)
+            .input(format!("{p}pwdata"), data_bit)
+            .input(format!("{p}pstrb"), data_bit / 8)
+            .output(format!("{p}prdata"), data_bit)
+            .output(format!("{p}pready"), 1)
+            .output(format!("{p}pslverr"), 1);
+        for s in &self.slaves {
+            let n = &s.name;
+            module = module
+                .output(format!("{n}_paddr"), s.addr_bit)
+                .output(format!("{n}_psel"), 1)
+                .output(format!("{n}_penable"), 1)
+                .output(format!("{n}_pwrite"), 1)
+                .output(format!("{n}_pprot"), 3)
+                .output(format!("{n}_pwdata"), data_bit)
+                .output(format!("{n}_pstrb"), data_bit / 8)
+                .input(format!("{n}_prdata"), data_bit)
+                .input(format!("{n}_pready"), 1)
+                .input(format!("{n}_pslverr"), 1);
+        }
+
+        // Decode
+        module = module
+            .logic(format!("{p}dec"), self.sel_bit(), 1)
+            .always_comb(self.decode(&format!("{p}paddr"), &format!("{p}dec")));
+        for (i, s) in self.slaves.iter().enumerate() {
+            let n = &s.name;
+            module = module.always_comb(
+                Stmt::begin()
+                    .assign(format!("{n}_paddr"), Self::offset(&format!("{p}paddr"), s))
+                    .assign(format!("{n}_psel"), format!("{p}psel && {p}dec == {i}"))
+                    .assign(format!("{n}_penable"), format!("{p}penable"))
+                    .assign(format!("{n}_pwrite"), format!("{p}pwrite"))
+                    .assign(format!("{n}_pprot"), format!("{p}pprot"))
+                    .assign(format!("{n}_pwdata"), format!("{p}pwdata"))
+                    .assign(format!("{n}_pstrb"), format!("{p}pstrb"))
+                    .end(),
+            );
+        }
+
+        // Response
+        let mut resp = Case::new(format!("{p}dec"));
+        for (i, s) in self.slaves.iter().enumerate() {
+            let n = &s.name;
+            resp = resp.case(
+                i,
+                Stmt::begin()
+                    .assign(format!("{p}prdata"), format!("{n}_prdata"))
+                    .assign(format!("{p}pready"), format!("{n}_pready"))
+                    .assign(format!("{p}pslverr"), format!("{n}_pslverr"))
+                    .end(),
+            );
+        }
+        resp = resp.default(
+            Stmt::begin()
+                .assign(format!("{p}prdata"), "0")
+                .assign(format!("{p}pready"), "1")
+                .assign(format!("{p}pslverr"), "1")
+                .end(),
+        );
+        module = module.always_comb(Stmt::Case(resp));
+
+        module
+    }
+
+    fn add_wishbone(self, mut module: Module) -> Module {
+        let p = self.prefix();
+        let (addr_bit, data_bit) = (self.addr_bit, self.data_bit);
+        let hole = self.slaves.len();
+
+        // IO Port
+        module = module
+            .input(format!("{p}cyc"), 1)
+            .input(format!("{p}stb"), 1)
+            .input(format!("{p}we"), 1)
+            .input(format!("{p}sel"), data_bit / 8)
+            .input(format!("{p}adr"), addr_bit)
+            .input(format!("{p}dat_w"), data_bit)
+            .output(format!("{p}dat_r"), data_bit)
+            .output(format!("{p}ack"), 1)
+            .output(format!("{p}err"), 1);
+        for s in &self.slaves {
+            let n = &s.name;
+            module = module
+                .output(format!("{n}_cyc"), 1)
+                .output(format!("{n}_stb"), 1)
+                .output(format!("{n}_we"), 1)
+                .output(format!("{n}_sel"), data_bit / 8)
+                .output(format!("{n}_adr"), s.addr_bit)
+                .output(format!("{n}_dat_w"), data_bit)
+                .input(format!("{n}_dat_r"), data_bit)
+                .input(format!("{n}_ack"), 1)
+                .input(format!("{n}_err"), 1);
+        }
+
+        // Decode
+        module = module
+            .logic(format!("{p}dec"), self.sel_bit(), 1)
+            .always_comb(self.decode(&format!("{p}adr"), &format!("{p}dec")));
+        for (i, s) in self.slaves.iter().enumerate() {
+            let n = &s.name;
+            module = module.always_comb(
+                Stmt::begin()
+                    .assign(format!("{n}_cyc"), format!("{p}cyc && {p}dec == {i}"))
+                    .assign(format!("{n}_stb"), format!("{p}stb && {p}dec == {i}"))
+                    .assign(format!("{n}_we"), format!("{p}we"))
+                    .assign(format!("{n}_sel"), format!("{p}sel"))
+                    .assign(format!("{n}_adr"), Self::offset(&format!("{p}adr"), s))
+                    .assign(format!("{n}_dat_w"), format!("{p}dat_w"))
+                    .end(),
+            );
+        }
+
+        // Hole Error
+        module = module.logic(format!("{p}herr"), 1, 1).add(
+            DFF::new(
+                Stmt::assign(format!("{p}herr"), "0"),
+                Stmt::assign(
+                    format!("{p}herr"),
+                    format!("{p}cyc && {p}stb && {p}dec == {hole} && !{p}herr"),
+                ),
+            )
+            .on(&self.clk, &self.rst),
+        );
+
+        // Response
+        let mut resp = Case::new(format!("{p}dec"));
+        for (i, s) in self.slaves.iter().enumerate() {
+            let n = &s.name;
+            resp = resp.case(
+                i,
+                Stmt::begin()
+                    .assign(format!("{p}dat_r"), format!("{n}_dat_r"))
+                    .assign(format!("{p}ack"), format!("{n}_ack"))
+                    .assign(format!("{p}err"), format!("{n}_err"))
+                    .end(),
+            );
+        }
+        resp = resp.default(
+            Stmt::begin()
+                .assign(format!("{p}dat_r"), "0")
+                .assign(format!("{p}ack"), "0")
+                .assign(format!("{p}err"), format!("{p}herr"))
+                .end(),
+        );
+        module = module.always_comb(Stmt::Case(resp));
+
+        module
+    }
+}
@@ -0,0 +1,94 @@
+use super::MemMap;
+use crate::util::{mask, sel};
+
+impl MemMap {
+    /// IP-XACT (IEEE 1685-2014) component with the registers as its memory map.
+    /// Register arrays are unrolled, and registers without fields get one field covering the register.
+    /// Offsets are in bytes, and the address block covers the registers.
+    pub fn ipxact(&self, vendor: &str, name: &str) -> Vec<String> {
+        let mut code: Vec<String> = vec![];
+        code.push(r#"<?xml version="1.0" encoding="UTF-8"?>"#.to_string());
+        code.push(r#"<ipxact:component xmlns:ipxact="http://www.accellera.org/XMLSchema/IPXACT/1685-2014" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:schemaLocation="http://www.accellera.org/XMLSchema/IPXACT/1685-2014 http://www.accellera.org/XMLSchema/IPXACT/1685-2014/index.xsd">"#.to_string());
+        code.push(format!("  <ipxact:vendor>{vendor}</ipxact:vendor>"));
+        code.push("  <ipxact:library>ruverta</ipxact:library>".to_string());
+        code.push(format!("  <ipxact:name>{name}</ipxact:name>"));
+        code.push("  <ipxact:version>1.0</ipxact:version>".to_string());
+        code.push("  <ipxact:memoryMaps>".to_string());
+        code.push("    <ipxact:memoryMap>".to_string());
+        code.push(format!("      <ipxact:name>{name}</ipxact:name>"));
+        code.push("      <ipxact:addressBlock>".to_string());
+        code.push("        <ipxact:name>regs</ipxact:name>".to_string());
+        code.push("        <ipxact:baseAddress>0</ipxact:baseAddress>".to_string());
+        code.push(format!(
+            "        <ipxact:range>{}</ipxact:range>",
+            self.extent()
+        ));
+        code.push(format!(
+            "        <ipxact:width>{}</ipxact:width>",
+            self.data_bit
+        ));
+        code.push("        <ipxact:usage>register</ipxact:usage>".to_string());
+        for reg in &self.regs {
+            let (access, modified) = reg.kind.xml_access();
+            let fields = if reg.fields.is_empty() {
+                vec![(reg.name.as_str(), 0, reg.bit)]
+            } else {
+                reg.fields
+                    .iter()
+                    .map(|f| (f.name.as_str(), f.lsb, f.bit))
+                    .collect()
+            };
+            for idx in 0..reg.len {
+                let reg_name = format!("{}{}", reg.name, sel(idx, reg.len));
+                let reg_name = reg_name.replace('[', "_").replace(']', "");
+                code.push("        <ipxact:register>".to_string());
+                code.push(format!("          <ipxact:name>{reg_name}</ipxact:name>"));
+                code.push(format!(
+                    "          <ipxact:addressOffset>{}</ipxact:addressOffset>",
+                    self.offset(reg.addr(idx))
+                ));
+                code.push(format!(
+                    "          <ipxact:size>{}</ipxact:size>",
+                    self.data_bit
+                ));
+                for (field_name, lsb, bit) in &fields {
+                    code.push("          <ipxact:field>".to_string());
+                    code.push(format!(
+                        "            <ipxact:name>{field_name}</ipxact:name>"
+                    ));
+                    code.push(format!(
+                        "            <ipxact:bitOffset>{lsb}</ipxact:bitOffset>"
+                    ));
+                    if reg.has_storage() {
+                        code.push("            <ipxact:resets>".to_string());
+                        code.push("              <ipxact:reset>".to_string());
+                        code.push(format!(
+                            "                <ipxact:value>{}</ipxact:value>",
+                            (reg.reset >> lsb) & mask(*bit)
+                        ));
+                        code.push("              </ipxact:reset>".to_string());
+                        code.push("            </ipxact:resets>".to_string());
+                    }
+                    code.push(format!(
+                        "            <ipxact:bitWidth>{bit}</ipxact:bitWidth>"
+                    ));
+                    code.push(format!(
+                        "            <ipxact:access>{access}</ipxact:access>"
+                    ));
+                    if let Some(modified) = modified {
+                        code.push(format!(
+                            "            <ipxact:modifiedWriteValue>{modified}</ipxact:modifiedWriteValue>"
+                        ));
+                    }
+                    code.push("          </ipxact:field>".to_string());
+                }
+                code.push("        </ipxact:register>".to_string());
+            }
+        }
+        code.push("      </ipxact:addressBlock>".to_string());
+        code.push("    </ipxact:memoryMap>".to_string());
+        code.push("  </ipxact:memoryMaps>".to_string());
+        code.push("</ipxact:component>".to_string());
+        code
+    }
+}
@@ -0,0 +1,106 @@
+use super::{MemMap, Reg, RegKind};
+use crate::util::mask;
+
+impl MemMap {
+    /// Markdown register map document.
+    /// It has a table of all registers with their byte offsets and a WaveDrom `reg` bitfield diagram for each register.
+    pub fn markdown(&self, title: &str) -> Vec<String> {
+        let mut code: Vec<String> = vec![];
+        code.push(format!("# {title}"));
+        code.push(String::new());
+        code.push("| Offset | Name | Access | Reset |".to_string());
+        code.push("| ------ | ---- | ------ | ----- |".to_string());
+        for reg in &self.regs {
+            let (offset, name) = if reg.len == 1 {
+                (format!("{:#x}", self.offset(reg.addr(0))), reg.name.clone())
+            } else {
+                (
+                    format!(
+                        "{:#x} + {:#x} * i",
+                        self.offset(reg.addr(0)),
+                        self.offset(reg.stride)
+                    ),
+                    format!("{}[{}]", reg.name, reg.len),
+                )
+            };
+            let reset = if reg.has_storage() {
+                format!("{:#x}", reg.reset)
+            } else {
+                "-".to_string()
+            };
+            code.push(format!(
+                "| {offset} | [{name}](#{}) | {} | {reset} |",
+                reg.name,
+                reg.kind.access()
+            ));
+        }
+        for reg in &self.regs {
+            code.push(String::new());
+            code.push(format!("## {}", reg.name));
+            code.push(String::new());
+            code.push("```wavedrom".to_string());
+            code.push(self.wavedrom(reg));
+            code.push("```".to_string());
+            if !reg.fields.is_empty() {
+                code.push(String::new());
+                code.push("| Bits | Field | Reset |".to_string());
+                code.push("| ---- | ----- | ----- |".to_string());
+                for field in &reg.fields {
+                    let bits = if field.bit == 1 {
+                        format!("{}", field.lsb)
+                    } else {
+                        format!("{}:{}", field.lsb + field.bit - 1, field.lsb)
+                    };
+                    let reset = if reg.has_storage() {
+                        format!("{:#x}", (reg.reset >> field.lsb) & mask(field.bit))
+                    } else {
+                        "-".to_string()
+                    };
+                    code.push(format!("| {bits} | {} | {reset} |", field.name));
+                }
+            }
+        }
+        code
+    }
+
+    /// WaveDrom `reg` JSON of one register, LSB first.
+    fn wavedrom(&self, reg: &Reg) -> String {
+        let attr = match reg.kind {
+            RegKind::ReadWrite => "rw",
+            RegKind::ReadOnly => "ro",
+            RegKind::WriteOnly => "wo",
+            RegKind::Trigger => "trig",
+            RegKind::IntStatus => "w1c",
+            RegKind::IntTest => "w1s",
+        };
+        let mut fields: Vec<(usize, usize, &str)> = if reg.fields.is_empty() {
+            vec![(0, reg.bit, reg.name.as_str())]
+        } else {
+            reg.fields
+                .iter()
+                .map(|f| (f.lsb, f.bit, f.name.as_str()))
+                .collect()
+        };
+        fields.sort();
+
+        let mut items: Vec<String> = vec![];
+        let mut next = 0;
+        for (lsb, bit, name) in fields {
+            if next < lsb {
+                items.push(format!("{{\"bits\": {}}}", lsb - next));
+            }
+            items.push(format!(
+                "{{\"bits\": {bit}, \"name\": \"{name}\", \"attr\": \"{attr}\"}}"
+            ));
+            next = lsb + bit;
+        }
+        if next < self.data_bit {
+            items.push(format!("{{\"bits\": {}}}", self.data_bit - next));
+        }
+        format!(
+            "{{\"reg\": [{}], \"config\": {{\"bits\": {}}}}}",
+            items.join(", "),
+            self.data_bit
+        )
+    }
+}
@@ -1,15 +1,33 @@
+mod ahb_lite;
+mod apb;
+mod axi4;
 mod axi_lite;
+mod bridge;
+mod c_header;
+mod cdc;
 mod common;
-#[cfg(feature = "unstable")]
+mod interconnect;
+mod ipxact;
+mod markdown;
+mod pac;
 mod pico;
+mod rdl;
+mod svd;
+mod wishbone;
 
-pub use axi_lite::AXILiteSlave;
-#[cfg(feature = "unstable")]
+pub use ahb_lite::AHBLiteSlave;
+pub use apb::{APBMaster, APBSlave};
+pub use axi4::{AXI4Master, AXI4Slave};
+pub use axi_lite::{AXILiteMaster, AXILiteSlave};
+pub use bridge::Bridge;
+pub use interconnect::Interconnect;
 pub use pico::PicoSlave;
+pub use rdl::RdlError;
+pub use wishbone::WishboneSlave;
 
 // ----------------------------------------------------------------------------
 
-use crate::util::sel;
+use crate::util::{clog2, sel};
 
 #[derive(Debug, Clone)]
 pub struct RegList {
@@ -17,34 +35,84 @@ pub struct RegList {
 }
 
 #[derive(Debug, Clone)]
-enum Reg {
-    ReadWrite {
-        name: String,
-        bit: usize,
-        len: usize,
-    },
-    ReadOnly {
-        name: String,
-        bit: usize,
-        len: usize,
-    },
-    WriteOnly {
-        name: String,
-        bit: usize,
-        len: usize,
-    },
-    Trigger {
-        name: String,
-    },
+struct Reg {
+    name: String,
+    kind: RegKind,
+    bit: usize,
+    len: usize,
+    base: Option<usize>,
+    stride: usize,
+    reset: usize,
+    hw_write: bool,
+    fields: Vec<Field>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RegKind {
+    ReadWrite,
+    ReadOnly,
+    WriteOnly,
+    Trigger,
+    /// Interrupt status, write 1 to clear
+    IntStatus,
+    /// Interrupt test, write 1 to set the interrupt status
+    IntTest,
+}
+
+#[derive(Debug, Clone)]
+struct Field {
+    name: String,
+    lsb: usize,
+    bit: usize,
+    irq: bool,
 }
 
 impl Reg {
-    fn len(&self) -> usize {
+    fn new(name: impl ToString, kind: RegKind, bit: usize, len: usize) -> Self {
+        Self {
+            name: name.to_string(),
+            kind,
+            bit,
+            len,
+            base: None,
+            stride: 1,
+            reset: 0,
+            hw_write: false,
+            fields: vec![],
+        }
+    }
+    fn addr(&self, idx: usize) -> usize {
+        self.base.unwrap() + idx * self.stride
+    }
+    /// Whether the bus slave holds the value (and resets it).
+    fn has_storage(&self) -> bool {
+        match self.kind {
+            RegKind::ReadOnly => self.hw_write,
+            RegKind::IntTest => false,
+            _ => true,
+        }
+    }
+}
+
+impl RegKind {
+    fn access(&self) -> &'static str {
         match self {
-            Self::ReadWrite { len, .. } => *len,
-            Self::ReadOnly { len, .. } => *len,
-            Self::WriteOnly { len, .. } => *len,
-            Self::Trigger { .. } => 1,
+            RegKind::ReadWrite => "read-write",
+            RegKind::ReadOnly => "read-only",
+            RegKind::WriteOnly => "write-only",
+            RegKind::Trigger => "trigger",
+            RegKind::IntStatus => "read, write 1 to clear",
+            RegKind::IntTest => "write 1 to set",
+        }
+    }
+    /// Access and modified write value in SVD / IP-XACT terms.
+    fn xml_access(&self) -> (&'static str, Option<&'static str>) {
+        match self {
+            RegKind::ReadWrite | RegKind::Trigger => ("read-write", None),
+            RegKind::ReadOnly => ("read-only", None),
+            RegKind::WriteOnly => ("write-only", None),
+            RegKind::IntStatus => ("read-write", Some("oneToClear")),
+            RegKind::IntTest => ("write-only", Some("oneToSet")),
         }
     }
 }
@@ -55,28 +123,92 @@ impl RegList {
     }
     pub fn read_write(mut self, name: impl ToString, bit: usize, len: usize) -> Self {
         assert!(0 < bit && 0 < len);
-        self.regs.push(Reg::ReadWrite {
-            name: name.to_string(),
-            bit,
-            len,
-        });
+        self.regs.push(Reg::new(name, RegKind::ReadWrite, bit, len));
         self
     }
     pub fn read_only(mut self, name: impl ToString, bit: usize, len: usize) -> Self {
         assert!(0 < bit && 0 < len);
-        self.regs.push(Reg::ReadOnly {
-            name: name.to_string(),
-            bit,
-            len,
-        });
+        self.regs.push(Reg::new(name, RegKind::ReadOnly, bit, len));
+        self
+    }
+    pub fn write_only(mut self, name: impl ToString, bit: usize, len: usize) -> Self {
+        assert!(0 < bit && 0 < len);
+        self.regs.push(Reg::new(name, RegKind::WriteOnly, bit, len));
         self
     }
     pub fn trigger(mut self, name: impl ToString) -> Self {
-        self.regs.push(Reg::Trigger {
+        self.regs.push(Reg::new(name, RegKind::Trigger, 1, 1));
+        self
+    }
+
+    /// Place the last added register at the register index `addr`, which is at byte `addr * data_bit / 8`.
+    /// Registers added after it continue from the end of this one.
+    pub fn base(mut self, addr: usize) -> Self {
+        let reg = self.regs.last_mut().expect("No register to place");
+        reg.base = Some(addr);
+        self
+    }
+
+    /// Space the elements of the last added register array `stride` registers apart.
+    pub fn stride(mut self, stride: usize) -> Self {
+        assert!(0 < stride);
+        let reg = self.regs.last_mut().expect("No register to place");
+        reg.stride = stride;
+        self
+    }
+
+    /// Reset value of the last added register.
+    pub fn reset(mut self, value: usize) -> Self {
+        let reg = self.regs.last_mut().expect("No register to reset");
+        assert!(
+            reg.bit >= usize::BITS as usize || value >> reg.bit == 0,
+            "Reset value is wider than register"
+        );
+        reg.reset = value;
+        self
+    }
+
+    /// Let hardware update the last added register through `<name>_hw_we` and `<name>_hw_d`.
+    /// When the bus and hardware write in the same cycle, the hardware write wins.
+    pub fn hw_write(mut self) -> Self {
+        let reg = self.regs.last_mut().expect("No register to update");
+        assert!(
+            reg.kind != RegKind::Trigger,
+            "Trigger can't be written by hardware"
+        );
+        reg.hw_write = true;
+        self
+    }
+
+    /// Name `bit` bits of the last added register starting at `lsb`.
+    pub fn field(mut self, name: impl ToString, lsb: usize, bit: usize) -> Self {
+        let reg = self.regs.last_mut().expect("No register to add field");
+        assert!(0 < bit && lsb + bit <= reg.bit, "Field is out of register");
+        assert!(
+            reg.fields
+                .iter()
+                .all(|f| lsb + bit <= f.lsb || f.lsb + f.bit <= lsb),
+            "Field overlaps"
+        );
+        reg.fields.push(Field {
             name: name.to_string(),
+            lsb,
+            bit,
+            irq: false,
         });
         self
     }
+
+    /// Mark the last added field as an interrupt source.
+    /// While the field is high, its bit in `int_status` is set.
+    pub fn interrupt(mut self) -> Self {
+        let reg = self.regs.last_mut().expect("No register to add interrupt");
+        assert!(reg.len == 1, "Interrupt source must not be an array");
+        let field = reg.fields.last_mut().expect("No field to add interrupt");
+        assert!(field.bit == 1, "Interrupt source must be 1 bit");
+        field.irq = true;
+        self
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -87,6 +219,8 @@ pub struct MemMap {
     addr_bit: usize,
     regs: Vec<Reg>,
     map: Vec<Entry>,
+    irqs: Vec<String>,
+    core: Option<cdc::Domain>,
 }
 
 #[derive(Debug, Clone)]
@@ -95,51 +229,206 @@ struct Entry {
     bit: usize,
     read: Option<String>,
     write: Option<String>,
+    op: WriteOp,
+    reset: usize,
+    hw: Option<HwWrite>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum WriteOp {
+    Store,
+    Clear,
+    Set,
+}
+
+#[derive(Debug, Clone)]
+struct HwWrite {
+    var: String,
+    we: String,
+    d: String,
 }
 
 impl RegList {
-    pub fn allocate_greedy(self, data_bit: usize, addr_bit: usize) -> MemMap {
-        let (list, _) = {
-            let mut addr = 0;
-            let mut list = vec![];
-            for entry in &self.regs {
-                for idx in 0..entry.len() {
-                    list.push(match entry {
-                        Reg::ReadWrite { name, bit, len } => Entry {
-                            read: Some(format!("{}{}", name, sel(idx, *len))),
-                            write: Some(format!("{}{}", name, sel(idx, *len))),
-                            bit: *bit,
-                            addr,
-                        },
-                        Reg::ReadOnly { name, bit, len } => Entry {
-                            read: Some(format!("{}{}", name, sel(idx, *len))),
-                            write: None,
-                            bit: *bit,
-                            addr,
-                        },
-                        Reg::WriteOnly { name, bit, len } => Entry {
-                            read: None,
-                            write: Some(format!("{}{}", name, sel(idx, *len))),
-                            bit: *bit,
-                            addr,
-                        },
-                        Reg::Trigger { name } => Entry {
-                            read: Some(format!("{}_resp", name)),
-                            write: Some(format!("{}_trig", name)),
-                            bit: 1,
-                            addr,
-                        },
-                    });
-                    addr += 1;
-                }
+    /// Lay the registers out on a bus of `data_bit` wide words and `addr_bit` wide byte addresses.
+    ///
+    /// Registers are indexed by word: register `i` is at byte offset `i * data_bit / 8`
+    /// on every bus slave and in every exported description.
+    pub fn allocate_greedy(mut self, data_bit: usize, addr_bit: usize) -> MemMap {
+        assert!(
+            data_bit.is_multiple_of(8) && (data_bit / 8).is_power_of_two(),
+            "Data bit width must be a power of two bytes"
+        );
+        let lane_bit = clog2(data_bit / 8).unwrap_or(0);
+        assert!(
+            lane_bit <= addr_bit,
+            "Address space must hold at least one word"
+        );
+        // Interrupt sources, in `int_status` bit order
+        let (irqs, names): (Vec<_>, Vec<_>) = self
+            .regs
+            .iter()
+            .flat_map(|reg| {
+                reg.fields.iter().filter(|f| f.irq).map(|f| {
+                    (
+                        format!("{}{}", reg.name, sel(f.lsb, reg.bit)),
+                        f.name.clone(),
+                    )
+                })
+            })
+            .unzip();
+        if !irqs.is_empty() {
+            let fields: Vec<Field> = names
+                .iter()
+                .enumerate()
+                .map(|(i, name)| Field {
+                    name: name.clone(),
+                    lsb: i,
+                    bit: 1,
+                    irq: false,
+                })
+                .collect();
+            for (name, kind) in [
+                ("int_status", RegKind::IntStatus),
+                ("int_enable", RegKind::ReadWrite),
+                ("int_test", RegKind::IntTest),
+            ] {
+                let mut reg = Reg::new(name, kind, irqs.len(), 1);
+                reg.fields = fields.clone();
+                self.regs.push(reg);
+            }
+        }
+
+        let limit = 1usize
+            .checked_shl((addr_bit - lane_bit) as u32)
+            .unwrap_or(usize::MAX);
+        let mut next = 0;
+        let mut map: Vec<Entry> = vec![];
+        for reg in &mut self.regs {
+            assert!(
+                reg.bit <= data_bit,
+                "Register {} is wider than the bus",
+                reg.name
+            );
+            let base = *reg.base.get_or_insert(next);
+            next = next.max(base + reg.len * reg.stride);
+            for idx in 0..reg.len {
+                let addr = reg.addr(idx);
+                assert!(
+                    addr < limit,
+                    "Register {} is out of address space",
+                    reg.name
+                );
+                assert!(
+                    map.iter().all(|entry| entry.addr != addr),
+                    "Register {} overlaps at index {}",
+                    reg.name,
+                    addr
+                );
+                let name = format!("{}{}", reg.name, sel(idx, reg.len));
+                let hw = reg.hw_write.then(|| HwWrite {
+                    var: name.clone(),
+                    we: format!("{}_hw_we{}", reg.name, sel(idx, reg.len)),
+                    d: format!("{}_hw_d{}", reg.name, sel(idx, reg.len)),
+                });
+                map.push(match reg.kind {
+                    RegKind::ReadWrite => Entry {
+                        read: Some(name.clone()),
+                        write: Some(name),
+                        op: WriteOp::Store,
+                        reset: reg.reset,
+                        bit: reg.bit,
+                        addr,
+                        hw,
+                    },
+                    RegKind::ReadOnly => Entry {
+                        read: Some(name),
+                        write: None,
+                        op: WriteOp::Store,
+                        reset: reg.reset,
+                        bit: reg.bit,
+                        addr,
+                        hw,
+                    },
+                    RegKind::WriteOnly => Entry {
+                        read: None,
+                        write: Some(name),
+                        op: WriteOp::Store,
+                        reset: reg.reset,
+                        bit: reg.bit,
+                        addr,
+                        hw,
+                    },
+                    RegKind::Trigger => Entry {
+                        read: Some(format!("{}_resp", reg.name)),
+                        write: Some(format!("{}_trig", reg.name)),
+                        op: WriteOp::Store,
+                        reset: reg.reset,
+                        bit: 1,
+                        addr,
+                        hw,
+                    },
+                    RegKind::IntStatus => Entry {
+                        read: Some(name.clone()),
+                        write: Some(name),
+                        op: WriteOp::Clear,
+                        reset: reg.reset,
+                        bit: reg.bit,
+                        addr,
+                        hw,
+                    },
+                    RegKind::IntTest => Entry {
+                        read: None,
+                        write: Some("int_status".to_string()),
+                        op: WriteOp::Set,
+                        reset: reg.reset,
+                        bit: reg.bit,
+                        addr,
+                        hw,
+                    },
+                });
             }
-            (list, addr)
-        };
-        return MemMap {
+        }
+        map.sort_by_key(|entry| entry.addr);
+        MemMap {
             data_bit,
             addr_bit,
             regs: self.regs,
-            map: list,
-        };
+            map,
+            irqs,
+            core: None,
+        }
+    }
+}
+
+impl MemMap {
+    /// Bytes in a bus word.
+    fn bytes(&self) -> usize {
+        self.data_bit / 8
+    }
+
+    /// Byte offset of the register at index `addr`.
+    fn offset(&self, addr: usize) -> usize {
+        addr * self.bytes()
+    }
+
+    /// Bytes covered by the registers, from offset 0 to the end of the last one.
+    fn extent(&self) -> usize {
+        self.map
+            .last()
+            .map_or(0, |entry| self.offset(entry.addr) + self.bytes())
+    }
+
+    /// Register index selected by the byte address `addr` of a bus slave.
+    pub(in crate::bus) fn word(&self, addr: &str) -> String {
+        let lane_bit = clog2(self.bytes()).unwrap_or(0);
+        if lane_bit == 0 {
+            addr.to_string()
+        } else if lane_bit == self.addr_bit {
+            "0".to_string()
+        } else if lane_bit + 1 == self.addr_bit {
+            format!("{addr}[{lane_bit}]")
+        } else {
+            format!("{addr}[{}:{lane_bit}]", self.addr_bit - 1)
+        }
     }
 }
@@ -0,0 +1,166 @@
+use super::{MemMap, Reg, RegKind};
+use crate::util::mask;
+
+impl MemMap {
+    /// Rust register access layer with typed read/write/modify for each register and field.
+    /// The generated module only depends on `core`, so it can be used from `no_std` firmware.
+    /// Registers are placed at their byte offsets from the `base` given to the block.
+    pub fn rust_pac(&self, name: &str) -> Vec<String> {
+        let ty = match self.data_bit {
+            8 => "u8",
+            16 => "u16",
+            32 => "u32",
+            64 => "u64",
+            _ => panic!("Data bit width must be 8, 16, 32 or 64"),
+        };
+        let block = camel(name);
+
+        let mut code: Vec<String> = vec![];
+        code.push(format!("//! Register access layer for `{name}`"));
+        code.push(String::new());
+        code.push("use core::ptr::{read_volatile, write_volatile};".to_string());
+        code.push(String::new());
+
+        // Register block
+        code.push(format!("/// `{name}` register block"));
+        code.push("#[derive(Debug, Clone, Copy)]".to_string());
+        code.push(format!("pub struct {block} {{"));
+        code.push("    base: usize,".to_string());
+        code.push("}".to_string());
+        code.push(String::new());
+        code.push(format!("impl {block} {{"));
+        code.push("    /// # Safety".to_string());
+        code.push(format!(
+            "    /// `base` must be the address of the `{name}` register block."
+        ));
+        code.push("    pub const unsafe fn new(base: usize) -> Self {".to_string());
+        code.push("        Self { base }".to_string());
+        code.push("    }".to_string());
+        for reg in &self.regs {
+            let reg_ty = camel(&reg.name);
+            if reg.len == 1 {
+                code.push(format!("    pub fn {}(&self) -> {reg_ty} {{", reg.name));
+                code.push(format!(
+                    "        {reg_ty} {{ addr: {} }}",
+                    offset(self.offset(reg.addr(0)))
+                ));
+            } else {
+                code.push(format!(
+                    "    pub fn {}(&self, i: usize) -> {reg_ty} {{",
+                    reg.name
+                ));
+                code.push(format!("        assert!(i < {});", reg.len));
+                code.push(format!(
+                    "        {reg_ty} {{ addr: {} + i * {:#x} }}",
+                    offset(self.offset(reg.addr(0))),
+                    self.offset(reg.stride)
+                ));
+            }
+            code.push("    }".to_string());
+        }
+        code.push("}".to_string());
+
+        // Registers
+        for reg in &self.regs {
+            code.push(String::new());
+            code.extend(pac_reg(reg, ty));
+        }
+        code
+    }
+}
+
+fn pac_reg(reg: &Reg, ty: &str) -> Vec<String> {
+    let reg_ty = camel(&reg.name);
+    let readable = !matches!(reg.kind, RegKind::WriteOnly | RegKind::IntTest);
+    let writable = !matches!(reg.kind, RegKind::ReadOnly);
+
+    let mut code: Vec<String> = vec![];
+    code.push(format!("/// `{}` : {}", reg.name, reg.kind.access()));
+    code.push("#[derive(Debug, Clone, Copy)]".to_string());
+    code.push(format!("pub struct {reg_ty} {{"));
+    code.push("    addr: usize,".to_string());
+    code.push("}".to_string());
+    code.push(String::new());
+    code.push(format!("impl {reg_ty} {{"));
+    code.push(format!("    pub const MASK: {ty} = {:#x};", mask(reg.bit)));
+    if reg.has_storage() {
+        code.push(format!("    pub const RESET: {ty} = {:#x};", reg.reset));
+    }
+    for field in &reg.fields {
+        let field_const = field.name.to_uppercase();
+        code.push(format!(
+            "    pub const {field_const}_SHIFT: u32 = {};",
+            field.lsb
+        ));
+        code.push(format!(
+            "    pub const {field_const}_MASK: {ty} = {:#x};",
+            mask(field.bit) << field.lsb
+        ));
+    }
+    if readable {
+        code.push(format!("    pub fn read(&self) -> {ty} {{"));
+        code.push(format!(
+            "        let val = unsafe {{ read_volatile(self.addr as *const {ty}) }};"
+        ));
+        code.push("        val & Self::MASK".to_string());
+        code.push("    }".to_string());
+    }
+    if writable {
+        code.push(format!("    pub fn write(&self, val: {ty}) {{"));
+        code.push(format!(
+            "        unsafe {{ write_volatile(self.addr as *mut {ty}, val & Self::MASK) }}"
+        ));
+        code.push("    }".to_string());
+    }
+    let modifiable = reg.kind == RegKind::ReadWrite;
+    if modifiable {
+        code.push(format!(
+            "    pub fn modify(&self, f: impl FnOnce({ty}) -> {ty}) {{"
+        ));
+        code.push("        self.write(f(self.read()))".to_string());
+        code.push("    }".to_string());
+    }
+    for field in &reg.fields {
+        let field_const = field.name.to_uppercase();
+        if readable {
+            code.push(format!("    pub fn {}(&self) -> {ty} {{", field.name));
+            code.push(format!(
+                "        (self.read() & Self::{field_const}_MASK) >> Self::{field_const}_SHIFT"
+            ));
+            code.push("    }".to_string());
+        }
+        if modifiable {
+            code.push(format!(
+                "    pub fn set_{}(&self, val: {ty}) {{",
+                field.name
+            ));
+            code.push(format!(
+                "        self.modify(|r| (r & !Self::{field_const}_MASK) | ((val << Self::{field_const}_SHIFT) & Self::{field_const}_MASK))"
+            ));
+            code.push("    }".to_string());
+        }
+    }
+    code.push("}".to_string());
+    code
+}
+
+/// Address `offset` bytes from the base of the block.
+fn offset(offset: usize) -> String {
+    if offset == 0 {
+        "self.base".to_string()
+    } else {
+        format!("self.base + {offset:#x}")
+    }
+}
+
+fn camel(name: &str) -> String {
+    name.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(c) => c.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
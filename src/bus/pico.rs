@@ -1,9 +1,8 @@
 use super::MemMap;
 use crate::{
     ext::DFF,
-    module::{Extension, Module},
-    stmt::{Case, Stmt},
-    util::range,
+    module::{Clock, Extension, Module, Reset},
+    stmt::Stmt,
 };
 
 // ----------------------------------------------------------------------------
@@ -11,20 +10,29 @@ use crate::{
 #[derive(Debug, Clone)]
 pub struct PicoSlave {
     name: String,
-    clk: String,
-    rst: String,
+    clk: Clock,
+    rst: Reset,
     mem: MemMap,
 }
 
 impl PicoSlave {
-    pub fn new(name: impl ToString, clk: impl ToString, rst: impl ToString, mem: MemMap) -> Self {
+    /// PicoRV32 native memory interface slave.
+    ///
+    /// `ready` is raised for one cycle per request, and a request writes only when `|wstrb`.
+    /// PicoRV32 issues word-aligned byte addresses, and register `i` is at byte `4 * i`.
+    pub fn new(
+        name: impl ToString,
+        clk: impl Into<Clock>,
+        rst: impl Into<Reset>,
+        mem: MemMap,
+    ) -> Self {
         assert!(mem.data_bit == 32, "Data bit width must be 32");
         assert!(mem.addr_bit <= 32, "Addr bit width must be <= 32");
 
         Self {
             name: name.to_string(),
-            clk: clk.to_string(),
-            rst: rst.to_string(),
+            clk: clk.into(),
+            rst: rst.into(),
             mem,
         }
     }
@@ -38,6 +46,9 @@ impl PicoSlave {
             wstrb: format!("{name}_wstrb"),
             wdata: format!("{name}_wdata"),
             rdata: format!("{name}_rdata"),
+            req: format!("{name}_req"),
+            wmask: format!("{name}_wmask"),
+            irq: format!("{name}_irq"),
         }
     }
 }
@@ -50,6 +61,9 @@ struct SignalNames {
     wstrb: String,
     wdata: String,
     rdata: String,
+    req: String,
+    wmask: String,
+    irq: String,
 }
 
 impl Extension for PicoSlave {
@@ -58,55 +72,68 @@ impl Extension for PicoSlave {
         let mem = &self.mem;
 
         // Regs
-        module = module.define_regs(mem);
+        module = module
+            .define_regs(mem)
+            .define_cdc(mem, &self.clk, &self.rst);
 
         // IO Port
         module = module
             .input(&bus.valid, 1)
-            .input(&bus.ready, 1)
-            .input(&bus.wstrb, mem.data_bit / 8)
+            .output(&bus.ready, 1)
             .input(&bus.addr, mem.addr_bit)
+            .input(&bus.wstrb, mem.data_bit / 8)
             .input(&bus.wdata, mem.data_bit)
-            .output(&bus.rdata, mem.data_bit);
+            .output(&bus.rdata, mem.data_bit)
+            .define_irq(mem, &bus.irq);
+
+        // Request
+        module = module.logic(&bus.req, 1, 1).always_comb(Stmt::assign(
+            &bus.req,
+            format!("{} && !{}", bus.valid, bus.ready),
+        ));
 
         // Write Logic
-        let init = {
-            let mut stmt = Stmt::begin();
-            for entry in &mem.map {
-                if let Some(name) = &entry.write {
-                    stmt = stmt.assign(&name, "0");
-                }
-            }
-            stmt.end()
-        };
-        let case = {
-            let mut cases = Case::new(&bus.addr);
-            for entry in &mem.map {
-                if let Some(name) = &entry.write {
-                    cases = cases.case(
-                        &format!("{}", entry.addr),
-                        Stmt::assign(name, &format!("{}{}", bus.wdata, range(entry.bit, 0))),
-                    );
-                }
-            }
-            cases.default(Stmt::empty())
-        };
-        module = module.add(DFF::sync(init, Stmt::begin().case(case).end()));
+        module = module.define_wmask(&bus.wstrb, &bus.wmask, mem.data_bit);
+        module = module.add(
+            DFF::new(
+                mem.reg_init(),
+                mem.reg_update(Stmt::If(
+                    format!("{} && |{}", bus.req, bus.wstrb),
+                    Box::new(
+                        Stmt::begin()
+                            .case(mem.reg_write(&mem.word(&bus.addr), &bus.wdata, Some(&bus.wmask)))
+                            .end(),
+                    ),
+                )),
+            )
+            .on(&self.clk, &self.rst),
+        );
 
         // Read Logic
-        let case = {
-            let mut cases = Case::new(&bus.addr);
-            for entry in &mem.map {
-                if let Some(name) = &entry.read {
-                    cases = cases.case(
-                        &format!("{}", entry.addr),
-                        Stmt::assign(&format!("{}{}", bus.rdata, range(entry.bit, 0)), name),
-                    );
-                }
-            }
-            cases.default(Stmt::assign(&bus.rdata, "0"))
-        };
-        module = module.always_comb(Stmt::begin().case(case).end());
+        module = module.add(
+            DFF::new(
+                Stmt::assign(&bus.rdata, "0"),
+                Stmt::begin()
+                    .r#if(
+                        format!("{} && !(|{})", bus.req, bus.wstrb),
+                        Stmt::begin()
+                            .assign(&bus.rdata, "0")
+                            .case(mem.reg_read(&mem.word(&bus.addr), &bus.rdata))
+                            .end(),
+                    )
+                    .end(),
+            )
+            .on(&self.clk, &self.rst),
+        );
+
+        // Pico Protocol
+        module = module.add(
+            DFF::new(
+                Stmt::assign(&bus.ready, "0"),
+                Stmt::assign(&bus.ready, &bus.req),
+            )
+            .on(&self.clk, &self.rst),
+        );
 
         module
     }
@@ -0,0 +1,652 @@
+//! SystemRDL import
+//!
+//! Supports a practical subset of SystemRDL 2.0:
+//!
+//! - `addrmap`, `regfile`, `reg` and `field` components, named or anonymous
+//! - instances with arrays (`[n]`), offsets (`@`) and strides (`+=`)
+//! - field positions (`[msb:lsb]` / `[width]`) and reset values (`= value`)
+//! - properties `sw`, `hw`, `reset`, `intr`, `regwidth`, `fieldwidth` and `default` assignments
+//!
+//! Other properties are accepted and ignored. Addresses are SystemRDL byte offsets.
+//! They are converted to register indices for `RegList::base` by the word of the addrmap,
+//! its `default regwidth` or 32 bits, so every register must be aligned to that word.
+//! `regwidth` is limited to 64 bits, the width of the reset values of `RegList`.
+
+use super::RegList;
+use std::{collections::HashMap, fmt};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RdlError {
+    pub line: usize,
+    pub msg: String,
+}
+
+impl fmt::Display for RdlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.msg)
+    }
+}
+
+impl std::error::Error for RdlError {}
+
+impl RegList {
+    /// Build a register list from SystemRDL source.
+    /// Registers in nested `regfile`s are named `<regfile>_<reg>`.
+    pub fn from_rdl(src: &str) -> Result<Self, RdlError> {
+        let toks = lex(src)?;
+        let mut parser = Parser {
+            toks,
+            pos: 0,
+            defs: HashMap::new(),
+            top: None,
+        };
+        parser.body(true)?;
+        let top = parser.top.ok_or(RdlError {
+            line: 1,
+            msg: "No addrmap".to_string(),
+        })?;
+
+        let word = regwidth(&Props::default().with(&top.items, false), top.line)? / 8;
+        let mut regs = vec![];
+        elab_block(&top, 0, "", &Props::default(), &mut regs)?;
+
+        let mut list = RegList::new();
+        for reg in regs {
+            list = reg.into_list(list, word)?;
+        }
+        Ok(list)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Clone, PartialEq)]
+enum Tok {
+    Ident(String),
+    Num(usize),
+    Str,
+    Punct(&'static str),
+}
+
+const PUNCTS: [&str; 12] = [
+    "+=", "%=", "->", "{", "}", "[", "]", ";", "=", "@", ":", ",",
+];
+
+fn lex(src: &str) -> Result<Vec<(Tok, usize)>, RdlError> {
+    let chars: Vec<char> = src.chars().collect();
+    let mut toks = vec![];
+    let mut line = 1;
+    let mut i = 0;
+    let err = |line, msg: String| RdlError { line, msg };
+    while i < chars.len() {
+        let c = chars[i];
+        let rest: String = chars[i..chars.len().min(i + 2)].iter().collect();
+        if c == '\n' {
+            line += 1;
+            i += 1;
+        } else if c.is_whitespace() {
+            i += 1;
+        } else if rest == "//" {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+        } else if rest == "/*" {
+            i += 2;
+            while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+                if chars[i] == '\n' {
+                    line += 1;
+                }
+                i += 1;
+            }
+            i += 2;
+        } else if c == '"' {
+            i += 1;
+            while i < chars.len() && chars[i] != '"' {
+                if chars[i] == '\\' {
+                    i += 1;
+                } else if chars[i] == '\n' {
+                    line += 1;
+                }
+                i += 1;
+            }
+            i += 1;
+            toks.push((Tok::Str, line));
+        } else if c.is_ascii_digit() || c == '\'' {
+            let begin = i;
+            while i < chars.len() && (chars[i].is_ascii_alphanumeric() || "_'".contains(chars[i])) {
+                i += 1;
+            }
+            let text: String = chars[begin..i].iter().collect();
+            let num = number(&text).ok_or(err(line, format!("Invalid number `{text}`")))?;
+            toks.push((Tok::Num(num), line));
+        } else if c.is_ascii_alphabetic() || c == '_' {
+            let begin = i;
+            while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            toks.push((Tok::Ident(chars[begin..i].iter().collect()), line));
+        } else if let Some(p) = PUNCTS.iter().find(|p| rest.starts_with(**p)) {
+            i += p.len();
+            toks.push((Tok::Punct(p), line));
+        } else {
+            return Err(err(line, format!("Unexpected character `{c}`")));
+        }
+    }
+    Ok(toks)
+}
+
+fn number(text: &str) -> Option<usize> {
+    let text = text.replace('_', "");
+    if let Some((_, val)) = text.split_once('\'') {
+        let (radix, digits) = match val.chars().next()? {
+            'h' | 'H' => (16, &val[1..]),
+            'd' | 'D' => (10, &val[1..]),
+            'o' | 'O' => (8, &val[1..]),
+            'b' | 'B' => (2, &val[1..]),
+            _ => return None,
+        };
+        usize::from_str_radix(digits, radix).ok()
+    } else if let Some(hex) = text.strip_prefix("0x").or(text.strip_prefix("0X")) {
+        usize::from_str_radix(hex, 16).ok()
+    } else {
+        text.parse().ok()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    AddrMap,
+    RegFile,
+    Reg,
+    Field,
+}
+
+#[derive(Debug, Clone)]
+struct Comp {
+    kind: Kind,
+    items: Vec<Item>,
+    line: usize,
+}
+
+#[derive(Debug, Clone)]
+enum Item {
+    Prop(String, Value),
+    Default(String, Value),
+    Inst(Comp, Vec<Inst>),
+}
+
+#[derive(Debug, Clone)]
+enum Value {
+    Num(usize),
+    Ident(String),
+    Str,
+}
+
+#[derive(Debug, Clone)]
+struct Inst {
+    name: String,
+    dim: Option<(usize, Option<usize>)>,
+    reset: Option<usize>,
+    offset: Option<usize>,
+    stride: Option<usize>,
+    line: usize,
+}
+
+struct Parser {
+    toks: Vec<(Tok, usize)>,
+    pos: usize,
+    defs: HashMap<String, Comp>,
+    top: Option<Comp>,
+}
+
+impl Parser {
+    fn peek(&self, n: usize) -> Option<&Tok> {
+        self.toks.get(self.pos + n).map(|(tok, _)| tok)
+    }
+    fn line(&self) -> usize {
+        self.toks
+            .get(self.pos)
+            .or(self.toks.last())
+            .map(|(_, line)| *line)
+            .unwrap_or(1)
+    }
+    fn err<T>(&self, msg: impl ToString) -> Result<T, RdlError> {
+        Err(RdlError {
+            line: self.line(),
+            msg: msg.to_string(),
+        })
+    }
+    fn next(&mut self) -> Result<Tok, RdlError> {
+        match self.toks.get(self.pos) {
+            Some((tok, _)) => {
+                self.pos += 1;
+                Ok(tok.clone())
+            }
+            None => self.err("Unexpected end of file"),
+        }
+    }
+    fn eat(&mut self, punct: &str) -> bool {
+        if matches!(self.peek(0), Some(Tok::Punct(p)) if *p == punct) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+    fn expect(&mut self, punct: &str) -> Result<(), RdlError> {
+        if self.eat(punct) {
+            Ok(())
+        } else {
+            self.err(format!("Expected `{punct}`"))
+        }
+    }
+    fn ident(&mut self) -> Result<String, RdlError> {
+        match self.next()? {
+            Tok::Ident(name) => Ok(name),
+            _ => {
+                self.pos -= 1;
+                self.err("Expected identifier")
+            }
+        }
+    }
+    fn num(&mut self) -> Result<usize, RdlError> {
+        match self.next()? {
+            Tok::Num(num) => Ok(num),
+            _ => {
+                self.pos -= 1;
+                self.err("Expected number")
+            }
+        }
+    }
+    fn value(&mut self) -> Result<Value, RdlError> {
+        match self.next()? {
+            Tok::Num(num) => Ok(Value::Num(num)),
+            Tok::Ident(name) => Ok(Value::Ident(name)),
+            Tok::Str => Ok(Value::Str),
+            Tok::Punct(_) => {
+                self.pos -= 1;
+                self.err("Expected value")
+            }
+        }
+    }
+
+    /// Items until `}` (or the end of file at the root)
+    fn body(&mut self, root: bool) -> Result<Vec<Item>, RdlError> {
+        let mut items = vec![];
+        loop {
+            match self.peek(0) {
+                None if root => return Ok(items),
+                Some(Tok::Punct("}")) if !root => return Ok(items),
+                _ => {}
+            }
+            let line = self.line();
+            let word = self.ident()?;
+            let kind = match word.as_str() {
+                "addrmap" => Some(Kind::AddrMap),
+                "regfile" => Some(Kind::RegFile),
+                "reg" => Some(Kind::Reg),
+                "field" => Some(Kind::Field),
+                _ => None,
+            };
+            if let Some(kind) = kind {
+                // Component definition
+                let name = match self.peek(0) {
+                    Some(Tok::Ident(_)) => Some(self.ident()?),
+                    _ => None,
+                };
+                self.expect("{")?;
+                let comp = Comp {
+                    kind,
+                    items: self.body(false)?,
+                    line,
+                };
+                self.expect("}")?;
+                if root && kind == Kind::AddrMap {
+                    self.top = Some(comp.clone());
+                }
+                if let Some(name) = name {
+                    self.defs.insert(name, comp.clone());
+                }
+                let insts = self.insts()?;
+                if !insts.is_empty() {
+                    items.push(Item::Inst(comp, insts));
+                }
+                self.expect(";")?;
+            } else if word == "default" {
+                let prop = self.ident()?;
+                let value = if self.eat("=") {
+                    self.value()?
+                } else {
+                    Value::Ident("true".to_string())
+                };
+                self.expect(";")?;
+                items.push(Item::Default(prop, value));
+            } else if let Some(comp) = self.defs.get(&word).cloned() {
+                // Instance of a named component
+                let insts = self.insts()?;
+                if insts.is_empty() {
+                    return self.err("Expected instance name");
+                }
+                self.expect(";")?;
+                items.push(Item::Inst(comp, insts));
+            } else if self.eat("=") {
+                let value = self.value()?;
+                self.expect(";")?;
+                items.push(Item::Prop(word, value));
+            } else if self.eat(";") {
+                items.push(Item::Prop(word, Value::Ident("true".to_string())));
+            } else {
+                return self.err(format!("Unsupported statement `{word}`"));
+            }
+        }
+    }
+
+    fn insts(&mut self) -> Result<Vec<Inst>, RdlError> {
+        let mut insts = vec![];
+        while let Some(Tok::Ident(_)) = self.peek(0) {
+            let line = self.line();
+            let name = self.ident()?;
+            let dim = if self.eat("[") {
+                let a = self.num()?;
+                let b = if self.eat(":") {
+                    Some(self.num()?)
+                } else {
+                    None
+                };
+                self.expect("]")?;
+                Some((a, b))
+            } else {
+                None
+            };
+            let reset = if self.eat("=") {
+                Some(self.num()?)
+            } else {
+                None
+            };
+            let offset = if self.eat("@") {
+                Some(self.num()?)
+            } else {
+                None
+            };
+            let stride = if self.eat("+=") {
+                Some(self.num()?)
+            } else {
+                None
+            };
+            if self.eat("%=") {
+                return self.err("`%=` is not supported");
+            }
+            insts.push(Inst {
+                name,
+                dim,
+                reset,
+                offset,
+                stride,
+                line,
+            });
+            if !self.eat(",") {
+                break;
+            }
+        }
+        Ok(insts)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Clone, Default)]
+struct Props {
+    props: HashMap<String, Value>,
+}
+
+impl Props {
+    fn with(&self, items: &[Item], own: bool) -> Self {
+        let mut props = self.clone();
+        for item in items {
+            match item {
+                Item::Default(name, value) => {
+                    props.props.insert(name.clone(), value.clone());
+                }
+                Item::Prop(name, value) if own => {
+                    props.props.insert(name.clone(), value.clone());
+                }
+                _ => {}
+            }
+        }
+        props
+    }
+    fn num(&self, name: &str) -> Option<usize> {
+        match self.props.get(name) {
+            Some(Value::Num(num)) => Some(*num),
+            Some(Value::Ident(id)) if id == "true" => Some(1),
+            Some(Value::Ident(id)) if id == "false" => Some(0),
+            _ => None,
+        }
+    }
+    fn access(&self, name: &str) -> (bool, bool) {
+        match self.props.get(name) {
+            Some(Value::Ident(id)) => (id.contains('r'), id.contains('w')),
+            _ => (true, true),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct RdlReg {
+    name: String,
+    addr: usize,
+    len: usize,
+    stride: usize,
+    fields: Vec<RdlField>,
+    line: usize,
+}
+
+#[derive(Debug)]
+struct RdlField {
+    name: String,
+    lsb: usize,
+    bit: usize,
+    sw: (bool, bool),
+    hw: (bool, bool),
+    reset: usize,
+    intr: bool,
+}
+
+fn elab_block(
+    comp: &Comp,
+    base: usize,
+    prefix: &str,
+    defaults: &Props,
+    regs: &mut Vec<RdlReg>,
+) -> Result<usize, RdlError> {
+    let defaults = defaults.with(&comp.items, false);
+    let mut next = base;
+    for item in &comp.items {
+        let Item::Inst(child, insts) = item else {
+            continue;
+        };
+        for inst in insts {
+            let err = |msg: &str| RdlError {
+                line: inst.line,
+                msg: format!("{}: {}", inst.name, msg),
+            };
+            match child.kind {
+                Kind::Reg => {
+                    let props = defaults.with(&child.items, true);
+                    let width = regwidth(&props, inst.line)?;
+                    let bytes = width / 8;
+                    let len = match inst.dim {
+                        None => 1,
+                        Some((len, None)) => len,
+                        Some(_) => return Err(err("Register array must be `[n]`")),
+                    };
+                    let stride = inst.stride.unwrap_or(bytes);
+                    let addr = match inst.offset {
+                        Some(offset) => base + offset,
+                        None => next.div_ceil(bytes) * bytes,
+                    };
+                    next = next.max(addr + len * stride);
+                    regs.push(RdlReg {
+                        name: format!("{prefix}{}", inst.name),
+                        addr,
+                        len,
+                        stride,
+                        fields: elab_fields(child, &defaults, width)?,
+                        line: inst.line,
+                    });
+                }
+                Kind::RegFile | Kind::AddrMap => {
+                    if inst.dim.is_some() {
+                        return Err(err("Regfile array is not supported"));
+                    }
+                    let addr = inst.offset.map(|offset| base + offset).unwrap_or(next);
+                    let end = elab_block(
+                        child,
+                        addr,
+                        &format!("{prefix}{}_", inst.name),
+                        &defaults,
+                        regs,
+                    )?;
+                    next = next.max(end);
+                }
+                Kind::Field => return Err(err("Field must be in a register")),
+            }
+        }
+    }
+    Ok(next)
+}
+
+/// `regwidth`, a power of two of at least 8 bits, and no wider than the register values of `RegList`.
+fn regwidth(props: &Props, line: usize) -> Result<usize, RdlError> {
+    let width = props.num("regwidth").unwrap_or(32);
+    if width < 8 || !width.is_power_of_two() {
+        return Err(RdlError {
+            line,
+            msg: format!("regwidth {width} is not a power of two of at least 8"),
+        });
+    }
+    if width > usize::BITS as usize {
+        return Err(RdlError {
+            line,
+            msg: format!("regwidth {width} is wider than {} bits", usize::BITS),
+        });
+    }
+    Ok(width)
+}
+
+fn elab_fields(reg: &Comp, defaults: &Props, width: usize) -> Result<Vec<RdlField>, RdlError> {
+    let defaults = defaults.with(&reg.items, false);
+    let mut fields: Vec<RdlField> = vec![];
+    let mut next = 0;
+    for item in &reg.items {
+        let Item::Inst(child, insts) = item else {
+            continue;
+        };
+        if child.kind != Kind::Field {
+            return Err(RdlError {
+                line: child.line,
+                msg: "Register can only contain fields".to_string(),
+            });
+        }
+        let props = defaults.with(&child.items, true);
+        for inst in insts {
+            let (lsb, bit) = match inst.dim {
+                Some((msb, Some(lsb))) if lsb <= msb => (lsb, msb - lsb + 1),
+                Some((lsb, Some(msb))) => (lsb, msb - lsb + 1),
+                Some((width, None)) => (inst.offset.unwrap_or(next), width),
+                None => (
+                    inst.offset.unwrap_or(next),
+                    props.num("fieldwidth").unwrap_or(1),
+                ),
+            };
+            let err = |msg: &str| RdlError {
+                line: inst.line,
+                msg: format!("{}: {}", inst.name, msg),
+            };
+            if bit == 0 || lsb + bit > width {
+                return Err(err("Field is out of register"));
+            }
+            if let Some(other) = fields
+                .iter()
+                .find(|f| lsb < f.lsb + f.bit && f.lsb < lsb + bit)
+            {
+                return Err(err(&format!("Field overlaps {}", other.name)));
+            }
+            let reset = inst.reset.or(props.num("reset")).unwrap_or(0);
+            if bit < usize::BITS as usize && reset >> bit != 0 {
+                return Err(err("Reset value is wider than field"));
+            }
+            next = lsb + bit;
+            fields.push(RdlField {
+                name: inst.name.clone(),
+                lsb,
+                bit,
+                sw: props.access("sw"),
+                hw: props.access("hw"),
+                reset,
+                intr: props.num("intr").unwrap_or(0) != 0,
+            });
+        }
+    }
+    Ok(fields)
+}
+
+impl RdlReg {
+    /// Add the register to `list`, at the index of its address in words of `word` bytes.
+    fn into_list(self, list: RegList, word: usize) -> Result<RegList, RdlError> {
+        let err = |msg: &str| RdlError {
+            line: self.line,
+            msg: format!("{}: {}", self.name, msg),
+        };
+        let bit = self
+            .fields
+            .iter()
+            .map(|f| f.lsb + f.bit)
+            .max()
+            .ok_or(err("Register has no field"))?;
+        let read = self.fields.iter().any(|f| f.sw.0);
+        let write = self.fields.iter().any(|f| f.sw.1);
+        let hw_write = write && self.fields.iter().any(|f| f.hw.1);
+        let reset = self
+            .fields
+            .iter()
+            .fold(0, |reset, f| reset | (f.reset << f.lsb));
+
+        let mut list = match (read, write) {
+            (true, true) => list.read_write(&self.name, bit, self.len),
+            (true, false) => list.read_only(&self.name, bit, self.len),
+            (false, true) => list.write_only(&self.name, bit, self.len),
+            (false, false) => return Err(err("Register is not accessible by software")),
+        };
+        if bit > word * 8 {
+            return Err(err("Register is wider than the word"));
+        }
+        if !self.addr.is_multiple_of(word) || (self.len > 1 && !self.stride.is_multiple_of(word)) {
+            return Err(err(&format!(
+                "Register is not aligned to the {}-bit word",
+                word * 8
+            )));
+        }
+        list = list
+            .base(self.addr / word)
+            .stride((self.stride / word).max(1));
+        if reset != 0 {
+            list = list.reset(reset);
+        }
+        if hw_write {
+            list = list.hw_write();
+        }
+        for field in &self.fields {
+            list = list.field(&field.name, field.lsb, field.bit);
+            if field.intr {
+                if field.bit != 1 || self.len != 1 {
+                    return Err(err("Interrupt field must be 1 bit in a single register"));
+                }
+                list = list.interrupt();
+            }
+        }
+        Ok(list)
+    }
+}
@@ -0,0 +1,88 @@
+use super::MemMap;
+use crate::util::mask;
+
+impl MemMap {
+    /// CMSIS-SVD description of the registers as a peripheral at `base`.
+    /// Offsets are in bytes, and the address block covers the registers.
+    pub fn svd(&self, name: &str, base: usize) -> Vec<String> {
+        let mut code: Vec<String> = vec![];
+        code.push(r#"<?xml version="1.0" encoding="utf-8"?>"#.to_string());
+        code.push(r#"<device schemaVersion="1.3" xmlns:xs="http://www.w3.org/2001/XMLSchema-instance" xs:noNamespaceSchemaLocation="CMSIS-SVD.xsd">"#.to_string());
+        code.push(format!("  <name>{name}</name>"));
+        code.push("  <version>1.0</version>".to_string());
+        code.push(format!("  <description>{name}</description>"));
+        code.push("  <addressUnitBits>8</addressUnitBits>".to_string());
+        code.push(format!("  <width>{}</width>", self.data_bit));
+        code.push(format!("  <size>{}</size>", self.data_bit));
+        code.push("  <peripherals>".to_string());
+        code.push("    <peripheral>".to_string());
+        code.push(format!("      <name>{name}</name>"));
+        code.push(format!("      <baseAddress>{base:#x}</baseAddress>"));
+        code.push("      <addressBlock>".to_string());
+        code.push("        <offset>0x0</offset>".to_string());
+        code.push(format!("        <size>{:#x}</size>", self.extent()));
+        code.push("        <usage>registers</usage>".to_string());
+        code.push("      </addressBlock>".to_string());
+        code.push("      <registers>".to_string());
+        for reg in &self.regs {
+            let (access, modified) = reg.kind.xml_access();
+            code.push("        <register>".to_string());
+            if reg.len == 1 {
+                code.push(format!("          <name>{}</name>", reg.name));
+            } else {
+                code.push(format!("          <dim>{}</dim>", reg.len));
+                code.push(format!(
+                    "          <dimIncrement>{:#x}</dimIncrement>",
+                    self.offset(reg.stride)
+                ));
+                code.push(format!("          <name>{}[%s]</name>", reg.name));
+            }
+            code.push(format!(
+                "          <description>{}</description>",
+                reg.kind.access()
+            ));
+            code.push(format!(
+                "          <addressOffset>{:#x}</addressOffset>",
+                self.offset(reg.addr(0))
+            ));
+            code.push(format!("          <size>{}</size>", self.data_bit));
+            code.push(format!("          <access>{access}</access>"));
+            if reg.has_storage() {
+                code.push(format!(
+                    "          <resetValue>{:#x}</resetValue>",
+                    reg.reset
+                ));
+                code.push(format!(
+                    "          <resetMask>{:#x}</resetMask>",
+                    mask(reg.bit)
+                ));
+            }
+            if !reg.fields.is_empty() {
+                code.push("          <fields>".to_string());
+                for field in &reg.fields {
+                    code.push("            <field>".to_string());
+                    code.push(format!("              <name>{}</name>", field.name));
+                    code.push(format!(
+                        "              <bitOffset>{}</bitOffset>",
+                        field.lsb
+                    ));
+                    code.push(format!("              <bitWidth>{}</bitWidth>", field.bit));
+                    code.push(format!("              <access>{access}</access>"));
+                    if let Some(modified) = modified {
+                        code.push(format!(
+                            "              <modifiedWriteValues>{modified}</modifiedWriteValues>"
+                        ));
+                    }
+                    code.push("            </field>".to_string());
+                }
+                code.push("          </fields>".to_string());
+            }
+            code.push("        </register>".to_string());
+        }
+        code.push("      </registers>".to_string());
+        code.push("    </peripheral>".to_string());
+        code.push("  </peripherals>".to_string());
+        code.push("</device>".to_string());
+        code
+    }
+}
@@ -0,0 +1,179 @@
+use super::MemMap;
+use crate::{
+    ext::DFF,
+    module::{Clock, Extension, Module, Reset},
+    stmt::Stmt,
+};
+
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, Clone)]
+pub struct WishboneSlave {
+    name: Option<String>,
+    clk: Clock,
+    rst: Reset,
+    mem: MemMap,
+    pipelined: bool,
+}
+
+impl WishboneSlave {
+    /// Wishbone B4 slave in classic mode.
+    pub fn new(
+        name: Option<&str>,
+        clk: impl Into<Clock>,
+        rst: impl Into<Reset>,
+        mem: MemMap,
+    ) -> Self {
+        Self {
+            name: name.map(|s| s.to_string()),
+            clk: clk.into(),
+            rst: rst.into(),
+            mem,
+            pipelined: false,
+        }
+    }
+
+    /// Use pipelined mode: a request is accepted every cycle and acknowledged on the next one.
+    pub fn pipelined(mut self) -> Self {
+        self.pipelined = true;
+        self
+    }
+
+    fn signal_names(&self) -> SignalNames {
+        let prefix = self
+            .name
+            .as_ref()
+            .map(|n| format!("{}_", n))
+            .unwrap_or_default();
+        SignalNames {
+            cyc: format!("{prefix}cyc"),
+            stb: format!("{prefix}stb"),
+            we: format!("{prefix}we"),
+            sel: format!("{prefix}sel"),
+            adr: format!("{prefix}adr"),
+            dat_w: format!("{prefix}dat_w"),
+            dat_r: format!("{prefix}dat_r"),
+            ack: format!("{prefix}ack"),
+            err: format!("{prefix}err"),
+            stall: format!("{prefix}stall"),
+            req: format!("{prefix}req"),
+            miss: format!("{prefix}miss"),
+            wmask: format!("{prefix}wmask"),
+            irq: format!("{prefix}irq"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct SignalNames {
+    cyc: String,
+    stb: String,
+    we: String,
+    sel: String,
+    adr: String,
+    dat_w: String,
+    dat_r: String,
+    ack: String,
+    err: String,
+    stall: String,
+    req: String,
+    miss: String,
+    wmask: String,
+    irq: String,
+}
+
+impl Extension for WishboneSlave {
+    fn add(self, mut module: Module) -> Module {
+        let bus = self.signal_names();
+        let mem = &self.mem;
+
+        // Regs
+        module = module
+            .define_regs(mem)
+            .define_cdc(mem, &self.clk, &self.rst);
+
+        // IO Port
+        module = module
+            .input(&bus.cyc, 1)
+            .input(&bus.stb, 1)
+            .input(&bus.we, 1)
+            .input(&bus.sel, mem.data_bit / 8)
+            .input(&bus.adr, mem.addr_bit)
+            .input(&bus.dat_w, mem.data_bit)
+            .output(&bus.dat_r, mem.data_bit)
+            .output(&bus.ack, 1)
+            .output(&bus.err, 1);
+        if self.pipelined {
+            module = module.output(&bus.stall, 1);
+        }
+        module = module.define_irq(mem, &bus.irq);
+
+        // Request
+        let req = if self.pipelined {
+            format!("{} && {} && !{}", bus.cyc, bus.stb, bus.stall)
+        } else {
+            format!("{} && {} && !{} && !{}", bus.cyc, bus.stb, bus.ack, bus.err)
+        };
+        module = module.logic(&bus.req, 1, 1).logic(&bus.miss, 1, 1);
+        if self.pipelined {
+            module = module.always_comb(Stmt::assign(&bus.stall, "0"));
+        }
+        module = module.always_comb(
+            Stmt::begin()
+                .assign(&bus.req, req)
+                .case(mem.reg_miss(&mem.word(&bus.adr), &bus.miss))
+                .end(),
+        );
+
+        // Write Logic
+        module = module.define_wmask(&bus.sel, &bus.wmask, mem.data_bit);
+        module = module.add(
+            DFF::new(
+                mem.reg_init(),
+                mem.reg_update(Stmt::If(
+                    format!("{} && {}", bus.req, bus.we),
+                    Box::new(
+                        Stmt::begin()
+                            .case(mem.reg_write(&mem.word(&bus.adr), &bus.dat_w, Some(&bus.wmask)))
+                            .end(),
+                    ),
+                )),
+            )
+            .on(&self.clk, &self.rst),
+        );
+
+        // Read Logic
+        module = module.add(
+            DFF::new(
+                Stmt::assign(&bus.dat_r, "0"),
+                Stmt::begin()
+                    .r#if(
+                        format!("{} && !{}", bus.req, bus.we),
+                        Stmt::begin()
+                            .assign(&bus.dat_r, "0")
+                            .case(mem.reg_read(&mem.word(&bus.adr), &bus.dat_r))
+                            .end(),
+                    )
+                    .end(),
+            )
+            .on(&self.clk, &self.rst),
+        );
+
+        // Wishbone Protocol
+        module = module.add(
+            DFF::new(
+                Stmt::begin()
+                    .assign(&bus.ack, "0")
+                    .assign(&bus.err, "0")
+                    .end(),
+                Stmt::begin()
+                    .assign(&bus.ack, format!("{} && !{}", bus.req, bus.miss))
+                    .assign(&bus.err, format!("{} && {}", bus.req, bus.miss))
+                    .end(),
+            )
+            .on(&self.clk, &self.rst),
+        );
+
+        module
+    }
+}
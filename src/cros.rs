@@ -0,0 +1,584 @@
+//! Clock / reset domains
+//!
+//! A domain is a pair of clock and reset signals. Modules declare the domains they work in,
+//! and ports, logics and `always_ff` blocks are tagged with one of them (`input a @ A`).
+//! [`Module::check_cdc`] then looks for crossings between them,
+//! and [`Module::sdc`] writes the timing constraints for them.
+
+use crate::{
+    ext::async_fifo::CDC_MEMORY,
+    module::{Clock, Module, Process, Reset},
+};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Domain {
+    pub name: String,
+    pub clock: Clock,
+    pub reset: Reset,
+    pub timing: Option<Timing>,
+}
+
+/// Clock timing of a domain, for [`Module::sdc`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Timing {
+    /// Clock from outside with a period in ns
+    Period(f64),
+    /// Clock divided by `by` from the clock of the domain `source`
+    Divided { source: String, by: usize },
+}
+
+impl Domain {
+    pub fn new(name: impl ToString, clock: impl Into<Clock>, reset: impl Into<Reset>) -> Self {
+        Self {
+            name: name.to_string(),
+            clock: clock.into(),
+            reset: reset.into(),
+            timing: None,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Read of a signal from another domain without a recognised synchronizer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CdcViolation {
+    /// Domain of the source signal
+    pub from: String,
+    /// Domain of the reader
+    pub to: String,
+    /// Signals from the source to the reader, through combinational logic
+    pub path: Vec<String>,
+}
+
+impl fmt::Display for CdcViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} -> {}: {}",
+            self.from,
+            self.to,
+            self.path.join(" -> ")
+        )
+    }
+}
+
+/// One assignment of a process.
+struct Write<'a> {
+    seq: bool,
+    /// Domain of the `always_ff`, `None` for `always_comb` and for flops on an undeclared clock
+    domain: Option<&'a str>,
+    var: &'a str,
+    val: &'a str,
+    /// Signals read by the value and by the index of `var`
+    reads: Vec<&'a str>,
+    /// Signals read by the enclosing conditions
+    cond_reads: Vec<&'a str>,
+}
+
+/// Writes of a module with the domains of the signals they touch.
+struct Analysis<'a> {
+    writes: Vec<Write<'a>>,
+    /// Buffers of async FIFOs, marked `CDC_MEMORY`
+    memories: HashSet<&'a str>,
+    widths: HashMap<&'a str, usize>,
+    /// Clock of each domain
+    clocks: HashMap<&'a str, &'a str>,
+    /// Domain of each signal, tagged or inferred
+    domains: HashMap<&'a str, &'a str>,
+    /// First stages of multi-flop synchronizers
+    first_stages: HashSet<&'a str>,
+    /// Outputs of multi-flop synchronizers
+    synced: HashSet<&'a str>,
+}
+
+impl Analysis<'_> {
+    /// `w` reads the async FIFO buffer `s` with a signal index.
+    fn memory(&self, w: &Write, s: &str) -> bool {
+        self.memories.contains(s) && indexed(w.val, s)
+    }
+
+    /// Signals of the domain `from` are read on another clock in the domain `to`.
+    /// Domains sharing a clock differ only in their resets and do not cross.
+    fn crosses(&self, from: &str, to: &str) -> bool {
+        self.clocks.get(from) != self.clocks.get(to)
+    }
+
+    /// `w` is a flop reading `s` from another domain.
+    fn foreign(&self, w: &Write, s: &str) -> bool {
+        match (w.domain, self.domains.get(s)) {
+            (Some(to), Some(&from)) => self.crosses(from, to),
+            _ => false,
+        }
+    }
+
+    /// The conditions of `w` derive from a synchronized signal, as the capture of a req/ack handshake.
+    fn qualified(&self, w: &Write) -> bool {
+        let mut stack = w.cond_reads.clone();
+        let mut seen = HashSet::new();
+        while let Some(s) = stack.pop() {
+            if self.synced.contains(s) {
+                return true;
+            }
+            if seen.insert(s) {
+                for c in self.writes.iter().filter(|c| !c.seq && c.var == s) {
+                    stack.extend(&c.reads);
+                    stack.extend(&c.cond_reads);
+                }
+            }
+        }
+        false
+    }
+
+    /// The synchronizer stage `w` cannot catch its source mid-change: it is one bit wide,
+    /// the source is a gray code `(x >> 1) ^ x`, or a req/ack handshake holds the source
+    /// (it only changes under a synchronized acknowledge, or `w` only captures under a synchronized request).
+    fn coherent(&self, w: &Write) -> bool {
+        let Some(src) = plain(w.val) else {
+            return false;
+        };
+        let mut writes = self
+            .writes
+            .iter()
+            .filter(|s| s.seq && s.var == src)
+            .peekable();
+        self.widths.get(w.var).is_some_and(|&bit| bit == 1)
+            || self.qualified(w)
+            || (writes.peek().is_some()
+                && writes
+                    .all(|s| idents(s.val).next().is_none() || gray(s.val) || self.qualified(s)))
+    }
+
+    /// Path from the register `s` comes from, through the combinational logic of its domain.
+    fn source<'b>(&'b self, s: &'b str) -> Vec<&'b str> {
+        let mut path = vec![s];
+        let mut cur = s;
+        while let Some(prev) = self
+            .writes
+            .iter()
+            .filter(|w| !w.seq && w.var == cur)
+            .find_map(|w| {
+                w.reads
+                    .iter()
+                    .filter(|r| !self.memory(w, r))
+                    .chain(&w.cond_reads)
+                    .find(|r| {
+                        let same = match (self.domains.get(*r), self.domains.get(cur)) {
+                            (Some(r), Some(cur)) => !self.crosses(r, cur),
+                            (r, cur) => r == cur,
+                        };
+                        same && !path.contains(r)
+                    })
+            })
+        {
+            path.push(prev);
+            cur = prev;
+        }
+        path.reverse();
+        path
+    }
+}
+
+impl Module {
+    fn analyze(&self) -> Analysis<'_> {
+        let signals = self.signals();
+        let known: HashSet<&str> = signals.iter().map(|&(name, ..)| name).collect();
+        let memories: HashSet<&str> = signals
+            .iter()
+            .filter(|&&(_, _, len, _, attrs)| len > 1 && attrs.iter().any(|a| a == CDC_MEMORY))
+            .map(|&(name, ..)| name)
+            .collect();
+
+        let mut writes = vec![];
+        for process in self.processes() {
+            let (seq, domain, stmt) = match process {
+                Process::Seq(domain, stmt) => (true, domain.map(|d| d.name.as_str()), stmt),
+                Process::Comb(stmt) => (false, None, stmt),
+            };
+            for (conds, var, val) in stmt.assigns() {
+                let (var, index) = var.split_at(var.find('[').unwrap_or(var.len()));
+                writes.push(Write {
+                    seq,
+                    domain,
+                    var,
+                    val,
+                    reads: idents(val)
+                        .chain(idents(index))
+                        .filter(|s| known.contains(s))
+                        .collect(),
+                    cond_reads: conds
+                        .into_iter()
+                        .flat_map(idents)
+                        .filter(|s| known.contains(s))
+                        .collect(),
+                });
+            }
+        }
+        let mut analysis = Analysis {
+            writes,
+            memories,
+            widths: signals.iter().map(|&(name, bit, ..)| (name, bit)).collect(),
+            clocks: self
+                .domains()
+                .iter()
+                .map(|d| (d.name.as_str(), d.clock.name.as_str()))
+                .collect(),
+            domains: HashMap::new(),
+            first_stages: HashSet::new(),
+            synced: HashSet::new(),
+        };
+
+        // Domains of signals
+        let mut domains: HashMap<&str, &str> = signals
+            .iter()
+            .filter_map(|&(name, _, _, tag, _)| Some((name, tag?)))
+            .collect();
+        for w in analysis.writes.iter().filter(|w| w.seq) {
+            if let Some(domain) = w.domain {
+                domains.entry(w.var).or_insert(domain);
+            }
+        }
+        loop {
+            let mut changed = false;
+            for w in analysis.writes.iter().filter(|w| !w.seq) {
+                if domains.contains_key(w.var) {
+                    continue;
+                }
+                let found = w
+                    .reads
+                    .iter()
+                    .filter(|s| !analysis.memory(w, s))
+                    .chain(&w.cond_reads)
+                    .find_map(|s| domains.get(s).copied());
+                if let Some(domain) = found {
+                    domains.insert(w.var, domain);
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+        analysis.domains = domains;
+
+        // Synchronizers
+        let writes = &analysis.writes;
+        analysis.first_stages = writes
+            .iter()
+            .filter(|w| {
+                let mut readers = writes
+                    .iter()
+                    .filter(|r| r.reads.contains(&w.var) || r.cond_reads.contains(&w.var))
+                    .peekable();
+                w.seq
+                    && plain(w.val).is_some_and(|src| analysis.foreign(w, src))
+                    && !w.cond_reads.iter().any(|s| analysis.foreign(w, s))
+                    && readers.peek().is_some()
+                    && readers.all(|r| {
+                        r.seq
+                            && r.domain
+                                .zip(w.domain)
+                                .is_some_and(|(r, w)| !analysis.crosses(r, w))
+                            && plain(r.val) == Some(w.var)
+                    })
+            })
+            .map(|w| w.var)
+            .collect();
+        analysis.synced = writes
+            .iter()
+            .filter(|w| {
+                w.seq && plain(w.val).is_some_and(|src| analysis.first_stages.contains(src))
+            })
+            .map(|w| w.var)
+            .collect();
+        analysis
+    }
+
+    /// Check every read across the declared domains.
+    ///
+    /// A signal belongs to the domain it is tagged with, or else to the domain of the `always_ff`
+    /// driving it, and `always_comb` outputs take the domain of their inputs.
+    /// Domains on the same clock do not cross, whatever their resets.
+    /// A read from another clock is accepted when it is
+    ///
+    /// - the first stage of a multi-flop synchronizer: a plain copy read only by plain copies in its domain,
+    ///   one bit wide unless the source is a gray code or held by a req/ack handshake,
+    /// - a capture under a condition derived from a synchronized signal, as in a req/ack handshake, or
+    /// - the buffer of an [`AsyncFIFO`](crate::ext::AsyncFIFO) indexed by a signal.
+    pub fn check_cdc(&self) -> Result<(), Vec<CdcViolation>> {
+        let analysis = self.analyze();
+        let Analysis {
+            writes,
+            domains,
+            first_stages,
+            ..
+        } = &analysis;
+
+        // Crossings
+        let mut violations: Vec<CdcViolation> = vec![];
+        let mut report = |from: &str, to: &str, path: Vec<&str>| {
+            let violation = CdcViolation {
+                from: from.to_string(),
+                to: to.to_string(),
+                path: path.into_iter().map(|s| s.to_string()).collect(),
+            };
+            if !violations.contains(&violation) {
+                violations.push(violation);
+            }
+        };
+        for w in writes {
+            let to = if w.seq {
+                w.domain
+            } else {
+                domains.get(w.var).copied()
+            };
+            let Some(to) = to else {
+                continue;
+            };
+            if let Some(src) = plain(w.val).filter(|_| w.seq && first_stages.contains(w.var)) {
+                if !analysis.coherent(w) {
+                    let mut path = analysis.source(src);
+                    path.push(w.var);
+                    report(domains[src], to, path);
+                }
+                continue;
+            }
+            if let Some(&tag) = domains.get(w.var) {
+                if w.seq && analysis.crosses(tag, to) {
+                    report(to, tag, vec![w.var]);
+                }
+            }
+            let qualified = w.seq && analysis.qualified(w);
+            for &s in &w.reads {
+                match domains.get(s) {
+                    Some(&from)
+                        if analysis.crosses(from, to) && !analysis.memory(w, s) && !qualified =>
+                    {
+                        let mut path = analysis.source(s);
+                        path.push(w.var);
+                        report(from, to, path);
+                    }
+                    _ => {}
+                }
+            }
+            for &s in &w.cond_reads {
+                match domains.get(s) {
+                    Some(&from) if analysis.crosses(from, to) => {
+                        let mut path = analysis.source(s);
+                        path.push(w.var);
+                        report(from, to, path);
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+
+    /// Timing constraints (SDC) derived from the domains.
+    ///
+    /// - `create_clock` / `create_generated_clock` for each clock net given a [`Timing`],
+    ///   named after the first domain on it,
+    /// - `set_clock_groups -asynchronous` between clocks of unrelated sources, and
+    /// - on the first stage of each synchronizer found as in [`Module::check_cdc`],
+    ///   `set_false_path` for a single bit, or `set_max_delay -datapath_only` of the faster period
+    ///   for a multi-bit gray code or handshake-held value, which must arrive within one cycle.
+    ///
+    /// Domains without a period, given or through their source, are left out,
+    /// and so are multi-bit synchronizers into or out of them, which a false path would leave unbounded.
+    pub fn sdc(&self) -> Vec<String> {
+        let mut code: Vec<String> = vec![];
+        let object = |signal: &str| {
+            if self.is_logic(signal) {
+                format!("[get_nets {signal}]")
+            } else {
+                format!("[get_ports {signal}]")
+            }
+        };
+
+        // Clocks
+        let mut created: Vec<&Domain> = vec![];
+        while let Some(domain) = self.domains().iter().find(|domain| {
+            self.clock_of(&created, &domain.name).is_none()
+                && match &domain.timing {
+                    Some(Timing::Period(_)) => true,
+                    Some(Timing::Divided { source, .. }) => {
+                        self.clock_of(&created, source).is_some()
+                    }
+                    None => false,
+                }
+        }) {
+            let net = object(&domain.clock.name);
+            match &domain.timing {
+                Some(Timing::Period(period)) => code.push(format!(
+                    "create_clock -name {} -period {period:.3} {net}",
+                    domain.name
+                )),
+                Some(Timing::Divided { source, by }) => {
+                    let source = self.get_domain(source).unwrap();
+                    code.push(format!(
+                        "create_generated_clock -name {} -source {} -divide_by {by} {net}",
+                        domain.name,
+                        object(&source.clock.name)
+                    ))
+                }
+                None => unreachable!(),
+            }
+            created.push(domain);
+        }
+        for domain in self.domains().iter().filter(|d| d.timing.is_some()) {
+            if let Some(clock) = self.clock_of(&created, &domain.name) {
+                assert!(
+                    self.clock_period(domain) == self.clock_period(clock),
+                    "Domains {} and {} on clock {} have different timings",
+                    clock.name,
+                    domain.name,
+                    domain.clock.name
+                );
+            }
+        }
+
+        // Clock Groups
+        let mut groups: Vec<(&str, Vec<&str>)> = vec![];
+        for domain in &created {
+            let root = &self.clock_root(domain).clock.name;
+            match groups.iter_mut().find(|(net, _)| net == root) {
+                Some((_, group)) => group.push(&domain.name),
+                None => groups.push((root, vec![&domain.name])),
+            }
+        }
+        if groups.len() > 1 {
+            let groups: Vec<String> = groups
+                .iter()
+                .map(|(_, group)| format!(" -group [get_clocks {{{}}}]", group.join(" ")))
+                .collect();
+            code.push(format!("set_clock_groups -asynchronous{}", groups.concat()));
+        }
+
+        // Synchronizers
+        let analysis = self.analyze();
+        let mut seen = HashSet::new();
+        for w in &analysis.writes {
+            let Some(src) = plain(w.val) else {
+                continue;
+            };
+            if !analysis.first_stages.contains(w.var) || !seen.insert(w.var) {
+                continue;
+            }
+            let cells = format!("[get_cells {}_reg*]", w.var);
+            let from = analysis
+                .domains
+                .get(src)
+                .and_then(|from| self.clock_of(&created, from));
+            let to = w.domain.and_then(|to| self.clock_of(&created, to));
+            let period = |clock: Option<&Domain>| self.clock_period(clock?);
+            match (analysis.widths.get(w.var), period(from), period(to)) {
+                (Some(1), ..) => code.push(format!("set_false_path -to {cells}")),
+                (_, Some(src_period), Some(dst_period)) if analysis.coherent(w) => {
+                    code.push(format!(
+                        "set_max_delay -datapath_only -from [get_clocks {}] -to {cells} {:.3}",
+                        from.unwrap().name,
+                        src_period.min(dst_period)
+                    ))
+                }
+                _ => {}
+            }
+        }
+
+        code
+    }
+
+    /// Clock among `created` on the clock net of the domain `name`.
+    fn clock_of<'a>(&self, created: &[&'a Domain], name: &str) -> Option<&'a Domain> {
+        let net = &self.get_domain(name)?.clock.name;
+        created.iter().find(|c| &c.clock.name == net).copied()
+    }
+
+    /// Domain at the top of the chain of divided clocks from `domain`.
+    fn clock_root<'a>(&'a self, mut domain: &'a Domain) -> &'a Domain {
+        while let Some(Timing::Divided { source, .. }) = &domain.timing {
+            domain = self.get_domain(source).unwrap();
+        }
+        domain
+    }
+
+    /// Clock period of `domain` in ns, if known.
+    fn clock_period(&self, domain: &Domain) -> Option<f64> {
+        match domain.timing.as_ref()? {
+            Timing::Period(period) => Some(*period),
+            Timing::Divided { source, by } => {
+                Some(self.clock_period(self.get_domain(source)?)? * *by as f64)
+            }
+        }
+    }
+}
+
+/// Identifiers in an expression, skipping numbers like `8'hff`.
+fn idents(expr: &str) -> impl Iterator<Item = &str> {
+    let bytes = expr.as_bytes();
+    let word = |c: u8| c.is_ascii_alphanumeric() || c == b'_' || c == b'$';
+    let mut found = vec![];
+    let mut i = 0;
+    while i < bytes.len() {
+        let begin = i;
+        if bytes[i].is_ascii_alphabetic() || bytes[i] == b'_' {
+            while i < bytes.len() && word(bytes[i]) {
+                i += 1;
+            }
+            found.push(&expr[begin..i]);
+        } else if bytes[i].is_ascii_digit() || bytes[i] == b'\'' {
+            i += 1;
+            while i < bytes.len() && (word(bytes[i]) || bytes[i] == b'\'') {
+                i += 1;
+            }
+        } else {
+            i += 1;
+        }
+    }
+    found.into_iter()
+}
+
+/// `src` when `val` is a plain copy `src` or `src[n]`.
+fn plain(val: &str) -> Option<&str> {
+    let val = val.trim();
+    let (name, index) = val.split_at(val.find('[').unwrap_or(val.len()));
+    let constant = index.is_empty()
+        || (index.starts_with('[')
+            && index.ends_with(']')
+            && index[1..index.len() - 1]
+                .bytes()
+                .all(|c| c.is_ascii_digit()));
+    let mut names = idents(name);
+    match (names.next(), names.next()) {
+        (Some(src), None) if src == name && constant => Some(src),
+        _ => None,
+    }
+}
+
+/// `val` is the gray code `(x >> 1) ^ x` or `x ^ (x >> 1)` of a signal `x`.
+fn gray(val: &str) -> bool {
+    let val: String = val.split_whitespace().collect();
+    let x = idents(&val).next().unwrap_or_default().to_string();
+    !x.is_empty() && (val == format!("({x}>>1)^{x}") || val == format!("{x}^({x}>>1)"))
+}
+
+/// `expr` reads `array` with an index given by a signal.
+fn indexed(expr: &str, array: &str) -> bool {
+    expr.match_indices(&format!("{array}[")).any(|(i, m)| {
+        let preceded = expr[..i]
+            .bytes()
+            .last()
+            .is_some_and(|c| c.is_ascii_alphanumeric() || c == b'_');
+        let index = expr[i + m.len()..].bytes().next();
+        !preceded && index.is_some_and(|c| c.is_ascii_alphabetic() || c == b'_')
+    })
+}
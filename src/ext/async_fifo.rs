@@ -0,0 +1,204 @@
+use crate::{
+    ext::{DFF, FIFO},
+    module::{Clock, Extension, Module, Reset},
+    stmt::Stmt,
+};
+
+/// Attribute marking the buffer as read across clocks at addresses synchronized on their own,
+/// the one memory that `check_cdc` lets a domain read from another.
+pub(crate) const CDC_MEMORY: &str = "CDC_MEMORY = \"TRUE\"";
+
+/// Asynchronous dual-clock FIFO
+///
+/// The buffer and the read data are those of [`FIFO`], with the write side on `wclk`
+/// and the read side on `rclk`. Pointers cross between the clocks as gray codes through
+/// two-flop synchronizers marked `ASYNC_REG`.
+/// Signals are prefixed with `name` like [`FIFO`]:
+/// `push`, `wdata`, `full`, `overflow` in the write domain and
+/// `pop`, `rdata`, `empty`, `underflow` in the read domain.
+#[derive(Debug, Clone)]
+pub struct AsyncFIFO {
+    pub name: String,
+    fifo: FIFO,
+    wclk: Clock,
+    wrst: Reset,
+    rclk: Clock,
+    rrst: Reset,
+}
+
+impl AsyncFIFO {
+    /// FIFO of `len` words of `bit` bits, written on `wclk`/`wrst` and read on `rclk`/`rrst`.
+    /// `len` must be a power of two for the gray-coded pointers.
+    pub fn new(
+        name: impl ToString,
+        bit: usize,
+        len: usize,
+        wclk: impl Into<Clock>,
+        wrst: impl Into<Reset>,
+        rclk: impl Into<Clock>,
+        rrst: impl Into<Reset>,
+    ) -> Self {
+        assert!(
+            len >= 2 && len.is_power_of_two(),
+            "Async FIFO depth must be a power of two"
+        );
+        Self {
+            name: name.to_string(),
+            fifo: FIFO::new(name, bit, len),
+            wclk: wclk.into(),
+            wrst: wrst.into(),
+            rclk: rclk.into(),
+            rrst: rrst.into(),
+        }
+    }
+
+    /// First-word-fall-through: `rdata` shows the head word while `!empty`, and `pop` consumes it.
+    /// Without it, `rdata` is loaded on the cycle after `pop`.
+    pub fn fwft(mut self) -> Self {
+        self.fifo = self.fifo.fwft();
+        self
+    }
+
+    /// Declare `push` / `wdata` / `pop` as inputs and the other signals as outputs of the module.
+    pub fn ports(mut self) -> Self {
+        self.fifo = self.fifo.ports();
+        self
+    }
+}
+
+impl Extension for AsyncFIFO {
+    fn add(self, mut module: Module) -> Module {
+        let fifo = &self.fifo;
+        let [push, wdata, full, overflow, pop, rdata, empty, underflow, we, re, buf] = [
+            "push",
+            "wdata",
+            "full",
+            "overflow",
+            "pop",
+            "rdata",
+            "empty",
+            "underflow",
+            "we",
+            "re",
+            "buf",
+        ]
+        .map(|s| fifo.signal(s));
+        let [wbin, wgray, wnext, rbin, rgray, rnext] =
+            ["wbin", "wgray", "wnext", "rbin", "rgray", "rnext"].map(|s| fifo.signal(s));
+        let [wq1_rgray, wq2_rgray, rq1_wgray, rq2_wgray] =
+            ["wq1_rgray", "wq2_rgray", "rq1_wgray", "rq2_wgray"].map(|s| fifo.signal(s));
+        let a = fifo.addr_width;
+
+        for (name, bit, input) in [
+            (&push, 1, true),
+            (&wdata, fifo.bit, true),
+            (&full, 1, false),
+            (&overflow, 1, false),
+            (&pop, 1, true),
+            (&rdata, fifo.bit, false),
+            (&empty, 1, false),
+            (&underflow, 1, false),
+        ] {
+            module = fifo.port(module, input, name, bit);
+        }
+        module = module
+            .logic(&buf, fifo.bit, fifo.len)
+            .attribute(&buf, CDC_MEMORY)
+            .logic(&we, 1, 1)
+            .logic(&re, 1, 1);
+        for ptr in [&wbin, &wgray, &wnext, &rbin, &rgray, &rnext] {
+            module = module.logic(ptr, a + 1, 1);
+        }
+
+        // Synchronizers
+        module = module
+            .chain(
+                &rgray,
+                &[wq1_rgray, wq2_rgray.clone()],
+                a + 1,
+                &self.wclk,
+                &self.wrst,
+            )
+            .chain(
+                &wgray,
+                &[rq1_wgray, rq2_wgray.clone()],
+                a + 1,
+                &self.rclk,
+                &self.rrst,
+            );
+
+        // Status
+        // Full when the write pointer is one lap ahead: the top two gray bits differ, the rest match.
+        let rest = if a == 1 {
+            String::new()
+        } else {
+            format!(", {wq2_rgray}[{}:0]", a - 2)
+        };
+        module = module.always_comb(
+            fifo.enables(
+                Stmt::begin()
+                    .assign(
+                        &full,
+                        format!("{wgray} == {{~{wq2_rgray}[{a}:{}]{rest}}}", a - 1),
+                    )
+                    .assign(&empty, format!("{rgray} == {rq2_wgray}")),
+            )
+            .assign(&wnext, format!("{wbin} + 1"))
+            .assign(&rnext, format!("{rbin} + 1"))
+            .end(),
+        );
+
+        // Write Domain
+        module = module.add(
+            DFF::new(
+                Stmt::begin()
+                    .assign(&wbin, "0")
+                    .assign(&wgray, "0")
+                    .assign(&overflow, "0")
+                    .end(),
+                Stmt::begin()
+                    .r#if(
+                        &we,
+                        Stmt::begin()
+                            .assign(&wbin, &wnext)
+                            .assign(&wgray, format!("({wnext} >> 1) ^ {wnext}"))
+                            .end(),
+                    )
+                    .assign(&overflow, format!("{push} && {full}"))
+                    .end(),
+            )
+            .on(&self.wclk, &self.wrst),
+        );
+
+        // Read Domain
+        module = module.add(
+            DFF::new(
+                Stmt::begin()
+                    .assign(&rbin, "0")
+                    .assign(&rgray, "0")
+                    .assign(&underflow, "0")
+                    .end(),
+                Stmt::begin()
+                    .r#if(
+                        &re,
+                        Stmt::begin()
+                            .assign(&rbin, &rnext)
+                            .assign(&rgray, format!("({rnext} >> 1) ^ {rnext}"))
+                            .end(),
+                    )
+                    .assign(&underflow, format!("{pop} && {empty}"))
+                    .end(),
+            )
+            .on(&self.rclk, &self.rrst),
+        );
+
+        // Buffer
+        fifo.storage(
+            module,
+            &format!("{wbin}[{}:0]", a - 1),
+            &format!("{rbin}[{}:0]", a - 1),
+            (&self.wclk, &self.wrst),
+            (&self.rclk, &self.rrst),
+        )
+    }
+}
@@ -1,57 +1,103 @@
 use crate::{
-    module::{Extension, Module, Sens},
+    module::{Clock, Extension, Module, Reset, ResetStyle, Sens},
     stmt::Stmt,
 };
 
 /// DFF (D Flip-Flop) configuration
 ///
 /// Represents a flip-flop configuration that can be added to a module.
+/// It runs on the module's clock and reset unless [`DFF::on`] gives other ones.
 #[derive(Debug, Clone)]
-pub enum DFF {
-    /// Synchronous reset flip-flop
-    Sync { init: Stmt, stmt: Stmt },
-    /// Asynchronous reset flip-flop
-    Async { init: Stmt, stmt: Stmt },
+pub struct DFF {
+    init: Stmt,
+    stmt: Stmt,
+    style: Option<ResetStyle>,
+    enable: Option<String>,
+    clock: Option<Clock>,
+    reset: Option<Reset>,
 }
 
 impl DFF {
+    /// Create a DFF configuration reset as its reset is configured
+    pub fn new(init: Stmt, stmt: Stmt) -> Self {
+        Self {
+            init,
+            stmt,
+            style: None,
+            enable: None,
+            clock: None,
+            reset: None,
+        }
+    }
+
     /// Create a synchronous reset DFF configuration
     pub fn sync(init: Stmt, stmt: Stmt) -> Self {
-        DFF::Sync { init, stmt }
+        Self {
+            style: Some(ResetStyle::Sync),
+            ..Self::new(init, stmt)
+        }
     }
 
     /// Create an asynchronous reset DFF configuration
     pub fn r#async(init: Stmt, stmt: Stmt) -> Self {
-        DFF::Async { init, stmt }
+        Self {
+            style: Some(ResetStyle::Async),
+            ..Self::new(init, stmt)
+        }
     }
-}
 
-impl Extension for DFF {
-    fn add(self, mut module: Module) -> Module {
-        let clk = module.clock.clone();
-        let rst = module.reset.clone();
-
-        match self {
-            DFF::Async { init, stmt } => {
-                module = module.always_ff(
-                    Sens::new().posedge(clk).negedge(rst.clone()),
-                    Stmt::begin()
-                        .r#if(&format!("!{}", rst), init)
-                        .r#else(stmt)
-                        .end(),
-                );
-            }
-            DFF::Sync { init, stmt } => {
-                module = module.always_ff(
-                    Sens::new().posedge(clk),
-                    Stmt::begin()
-                        .r#if(&format!("!{}", rst), init)
-                        .r#else(stmt)
-                        .end(),
-                );
-            }
+    /// Create a DFF configuration without reset, for datapath flops
+    pub fn no_reset(stmt: Stmt) -> Self {
+        Self {
+            style: Some(ResetStyle::None),
+            ..Self::new(Stmt::empty(), stmt)
         }
+    }
 
-        module
+    /// Update only while `enable` is true. The reset does not wait for it.
+    pub fn enable(mut self, enable: impl ToString) -> Self {
+        self.enable = Some(enable.to_string());
+        self
+    }
+
+    /// Clock by `clock` and reset by `reset` instead of the module's clock and reset.
+    pub fn on(mut self, clock: impl Into<Clock>, reset: impl Into<Reset>) -> Self {
+        self.clock = Some(clock.into());
+        self.reset = Some(reset.into());
+        self
+    }
+
+    fn always(self, clock: &Clock, reset: &Reset) -> (Sens, Stmt) {
+        let sens = Sens::new().clock(clock);
+        let style = match reset.style {
+            ResetStyle::None => ResetStyle::None,
+            style => self.style.unwrap_or(style),
+        };
+        let sens = match style {
+            ResetStyle::Async => sens.reset(reset),
+            _ => sens,
+        };
+        let stmt = match (style, self.enable) {
+            (ResetStyle::None, None) => self.stmt,
+            (ResetStyle::None, Some(enable)) => Stmt::If(enable, Box::new(self.stmt)),
+            (_, None) => Stmt::begin()
+                .r#if(reset.active(), self.init)
+                .r#else(self.stmt)
+                .end(),
+            (_, Some(enable)) => Stmt::begin()
+                .r#if(reset.active(), self.init)
+                .elif(enable, self.stmt)
+                .end(),
+        };
+        (sens, stmt)
+    }
+}
+
+impl Extension for DFF {
+    fn add(self, module: Module) -> Module {
+        let clock = self.clock.clone().unwrap_or_else(|| module.clock.clone());
+        let reset = self.reset.clone().unwrap_or_else(|| module.reset.clone());
+        let (sens, stmt) = self.always(&clock, &reset);
+        module.always_ff(sens, stmt)
     }
 }
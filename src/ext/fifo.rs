@@ -1,39 +1,255 @@
 use crate::{
-    module::{Extension, Module},
+    ext::DFF,
+    module::{Clock, Extension, Module, Reset},
+    stmt::{Block, Stmt},
     util::clog2,
 };
 
+/// Synchronous FIFO
+///
+/// Signals are prefixed with `name`:
+/// `push`, `wdata`, `pop`, `rdata`, `full`, `empty`, `count`, `overflow`, `underflow`,
+/// and `almost_full` / `almost_empty` when their thresholds are set.
+/// They are internal logics for the caller to drive and read, unless `ports` makes them module ports.
+///
+/// A `push` while `full` is dropped and flagged by `overflow`, even when a `pop` frees an entry in the same cycle.
 #[derive(Debug, Clone)]
 pub struct FIFO {
     pub name: String,
-    bit: usize,
-    len: usize,
-    addr_width: usize,
+    pub(super) bit: usize,
+    pub(super) len: usize,
+    pub(super) addr_width: usize,
     buf: String,
     rptr: String,
     wptr: String,
+    almost_full: Option<usize>,
+    almost_empty: Option<usize>,
+    fwft: bool,
+    ports: bool,
+    clock: Option<Clock>,
+    reset: Option<Reset>,
 }
 
 impl FIFO {
+    /// FIFO of `len` words of `bit` bits. `len` does not have to be a power of two.
     pub fn new(name: impl ToString, bit: usize, len: usize) -> Self {
+        assert!(len > 0, "FIFO must have at least one entry");
         let name: String = name.to_string();
         Self {
             name: name.clone(),
             bit,
             len,
-            addr_width: clog2(len).unwrap_or(1),
+            addr_width: clog2(len).unwrap_or(1).max(1),
             buf: format!("{name}_buf"),
             rptr: format!("{name}_rptr"),
             wptr: format!("{name}_wptr"),
+            almost_full: None,
+            almost_empty: None,
+            fwft: false,
+            ports: false,
+            clock: None,
+            reset: None,
         }
     }
+
+    /// Raise `almost_full` while `count >= level`, with `level` at most `len`.
+    pub fn almost_full(mut self, level: usize) -> Self {
+        assert!(
+            level <= self.len,
+            "Almost-full level must not exceed the FIFO depth"
+        );
+        self.almost_full = Some(level);
+        self
+    }
+
+    /// Raise `almost_empty` while `count <= level`, with `level` at most `len`.
+    pub fn almost_empty(mut self, level: usize) -> Self {
+        assert!(
+            level <= self.len,
+            "Almost-empty level must not exceed the FIFO depth"
+        );
+        self.almost_empty = Some(level);
+        self
+    }
+
+    /// First-word-fall-through: `rdata` shows the head word while `!empty`, and `pop` consumes it.
+    /// Without it, `rdata` is loaded on the cycle after `pop`.
+    pub fn fwft(mut self) -> Self {
+        self.fwft = true;
+        self
+    }
+
+    /// Declare `push` / `wdata` / `pop` as inputs and the other signals as outputs of the module.
+    pub fn ports(mut self) -> Self {
+        self.ports = true;
+        self
+    }
+
+    /// Clock by `clock` and reset by `reset` instead of the module's clock and reset.
+    pub fn on(mut self, clock: impl Into<Clock>, reset: impl Into<Reset>) -> Self {
+        self.clock = Some(clock.into());
+        self.reset = Some(reset.into());
+        self
+    }
+
+    pub(super) fn signal(&self, name: &str) -> String {
+        format!("{}_{name}", self.name)
+    }
+
+    /// Pointer increment, wrapping at `len` even when it is not a power of two.
+    fn next(&self, ptr: &str) -> String {
+        if self.len.is_power_of_two() {
+            format!("{ptr} + 1")
+        } else {
+            format!("{ptr} == {} ? 0 : {ptr} + 1", self.len - 1)
+        }
+    }
+
+    /// Declare a user-facing signal as a port or a logic.
+    pub(super) fn port(&self, module: Module, input: bool, name: &str, bit: usize) -> Module {
+        match (self.ports, input) {
+            (false, _) => module.logic(name, bit, 1),
+            (true, true) => module.input(name, bit),
+            (true, false) => module.output(name, bit),
+        }
+    }
+
+    /// Write and read enables: `push` and `pop` gated by `full` and `empty`.
+    pub(super) fn enables(&self, status: Block) -> Block {
+        let [push, pop, full, empty, we, re] =
+            ["push", "pop", "full", "empty", "we", "re"].map(|s| self.signal(s));
+        status
+            .assign(&we, format!("{push} && !{full}"))
+            .assign(&re, format!("{pop} && !{empty}"))
+    }
+
+    /// Buffer written with `wdata` at `waddr` on `we` in the write domain,
+    /// and `rdata` read from `raddr` on `re` in the read domain (or shown directly with `fwft`).
+    pub(super) fn storage(
+        &self,
+        module: Module,
+        waddr: &str,
+        raddr: &str,
+        (wclk, wrst): (&Clock, &Reset),
+        (rclk, rrst): (&Clock, &Reset),
+    ) -> Module {
+        let [wdata, rdata, we, re] = ["wdata", "rdata", "we", "re"].map(|s| self.signal(s));
+        let mut module = module.add(
+            DFF::no_reset(Stmt::assign(format!("{}[{waddr}]", self.buf), &wdata))
+                .enable(&we)
+                .on(wclk, wrst),
+        );
+        let head = format!("{}[{raddr}]", self.buf);
+        if self.fwft {
+            module = module.always_comb(Stmt::assign(&rdata, head));
+        } else {
+            module = module.add(
+                DFF::new(
+                    Stmt::assign(&rdata, "0"),
+                    Stmt::begin().r#if(&re, Stmt::assign(&rdata, head)).end(),
+                )
+                .on(rclk, rrst),
+            );
+        }
+        module
+    }
 }
 
 impl Extension for FIFO {
-    fn add(self, module: Module) -> Module {
-        module
+    fn add(self, mut module: Module) -> Module {
+        let [push, wdata, pop, rdata, full, empty, count, overflow, underflow, we, re] = [
+            "push",
+            "wdata",
+            "pop",
+            "rdata",
+            "full",
+            "empty",
+            "count",
+            "overflow",
+            "underflow",
+            "we",
+            "re",
+        ]
+        .map(|s| self.signal(s));
+        let count_width = clog2(self.len + 1).unwrap_or(1).max(1);
+        let clock = self.clock.clone().unwrap_or(module.clock.clone());
+        let reset = self.reset.clone().unwrap_or(module.reset.clone());
+
+        for (name, bit, input) in [
+            (&push, 1, true),
+            (&wdata, self.bit, true),
+            (&pop, 1, true),
+            (&rdata, self.bit, false),
+            (&full, 1, false),
+            (&empty, 1, false),
+            (&count, count_width, false),
+            (&overflow, 1, false),
+            (&underflow, 1, false),
+        ] {
+            module = self.port(module, input, name, bit);
+        }
+        module = module
             .logic(&self.buf, self.bit, self.len)
             .logic(&self.rptr, self.addr_width, 1)
             .logic(&self.wptr, self.addr_width, 1)
+            .logic(&we, 1, 1)
+            .logic(&re, 1, 1);
+
+        // Status
+        let mut status = self.enables(
+            Stmt::begin()
+                .assign(&full, format!("{count} == {}", self.len))
+                .assign(&empty, format!("{count} == 0")),
+        );
+        if let Some(level) = self.almost_full {
+            let almost_full = self.signal("almost_full");
+            module = self.port(module, false, &almost_full, 1);
+            status = status.assign(&almost_full, format!("{count} >= {level}"));
+        }
+        if let Some(level) = self.almost_empty {
+            let almost_empty = self.signal("almost_empty");
+            module = self.port(module, false, &almost_empty, 1);
+            status = status.assign(&almost_empty, format!("{count} <= {level}"));
+        }
+        module = module.always_comb(status.end());
+
+        // Pointers
+        module = module.add(
+            DFF::new(
+                Stmt::begin()
+                    .assign(&self.wptr, "0")
+                    .assign(&self.rptr, "0")
+                    .assign(&count, "0")
+                    .assign(&overflow, "0")
+                    .assign(&underflow, "0")
+                    .end(),
+                Stmt::begin()
+                    .r#if(&we, Stmt::assign(&self.wptr, self.next(&self.wptr)))
+                    .r#if(&re, Stmt::assign(&self.rptr, self.next(&self.rptr)))
+                    .r#if(
+                        format!("{we} && !{re}"),
+                        Stmt::assign(&count, format!("{count} + 1")),
+                    )
+                    .elif(
+                        format!("!{we} && {re}"),
+                        Stmt::assign(&count, format!("{count} - 1")),
+                    )
+                    .assign(&overflow, format!("{push} && {full}"))
+                    .assign(&underflow, format!("{pop} && {empty}"))
+                    .end(),
+            )
+            .on(&clock, &reset),
+        );
+
+        // Buffer
+        module = self.storage(
+            module,
+            &self.wptr,
+            &self.rptr,
+            (&clock, &reset),
+            (&clock, &reset),
+        );
+
+        module
     }
 }
@@ -3,14 +3,18 @@
 //! This module contains various hardware component implementations that extend Module
 //! functionality through the Extension trait.
 
+pub mod async_fifo;
 pub mod comb;
 pub mod dff;
 pub mod fifo;
 pub mod state_machine;
 pub mod stream;
+pub mod sync;
 
+pub use async_fifo::AsyncFIFO;
 pub use comb::Comb;
 pub use dff::DFF;
 pub use fifo::FIFO;
 pub use state_machine::StateMachine;
 pub use stream::{Stream, StreamConfig};
+pub use sync::{ResetSync, SyncBit, SyncHandshake, SyncPulse};
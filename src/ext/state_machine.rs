@@ -1,5 +1,5 @@
 use crate::{
-    module::{Extension, Module},
+    module::{Clock, Extension, Module, Reset},
     stmt::{Case, Stmt},
     util::clog2,
 };
@@ -10,6 +10,7 @@ use crate::{
 pub struct StateMachine {
     state_var: String,
     states: Vec<State>,
+    domain: Option<(Clock, Reset)>,
 }
 
 #[derive(Debug, Clone)]
@@ -30,9 +31,16 @@ impl StateMachine {
         StateMachine {
             state_var: state_var.to_string(),
             states: Vec::new(),
+            domain: None,
         }
     }
 
+    /// Clock by `clock` and reset by `reset` instead of the module's clock and reset.
+    pub fn on(mut self, clock: impl Into<Clock>, reset: impl Into<Reset>) -> Self {
+        self.domain = Some((clock.into(), reset.into()));
+        self
+    }
+
     pub fn state(self, name: impl ToString) -> StateBuilder {
         StateBuilder {
             state_machine: self,
@@ -86,7 +94,7 @@ impl Extension for StateMachine {
 
         use super::DFF;
 
-        module = module.add(DFF::sync(
+        let dff = DFF::new(
             Stmt::assign(&self.state_var, "0"),
             Stmt::begin()
                 .case({
@@ -105,7 +113,10 @@ impl Extension for StateMachine {
                     cases
                 })
                 .end(),
-        ));
-        module
+        );
+        match self.domain {
+            Some((clock, reset)) => module.add(dff.on(clock, reset)),
+            None => module.add(dff),
+        }
     }
 }
@@ -0,0 +1,309 @@
+use crate::{
+    ext::DFF,
+    module::{Clock, Extension, Module, Polarity, Reset},
+    stmt::Stmt,
+};
+
+/// Attribute keeping synchronizer flops together and away from retiming.
+const ASYNC_REG: &str = "ASYNC_REG = \"TRUE\"";
+
+// ----------------------------------------------------------------------------
+
+/// Multi-flop synchronizer of a level `{name}_in` into `{name}_out` on `clk`/`rst`.
+#[derive(Debug, Clone)]
+pub struct SyncBit {
+    pub name: String,
+    clk: Clock,
+    rst: Reset,
+    stages: usize,
+}
+
+impl SyncBit {
+    pub fn new(name: impl ToString, clk: impl Into<Clock>, rst: impl Into<Reset>) -> Self {
+        Self {
+            name: name.to_string(),
+            clk: clk.into(),
+            rst: rst.into(),
+            stages: 2,
+        }
+    }
+
+    /// Number of flops, 2 by default.
+    pub fn stages(mut self, stages: usize) -> Self {
+        assert!(stages >= 2, "Synchronizer needs at least two stages");
+        self.stages = stages;
+        self
+    }
+}
+
+impl Extension for SyncBit {
+    fn add(self, module: Module) -> Module {
+        let input = format!("{}_in", self.name);
+        let output = format!("{}_out", self.name);
+        let mut chain: Vec<String> = (1..self.stages)
+            .map(|i| format!("{}_s{i}", self.name))
+            .collect();
+        chain.push(output);
+        module
+            .logic(&input, 1, 1)
+            .chain(&input, &chain, 1, &self.clk, &self.rst)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Pulse synchronizer from `src_clk` to `dst_clk`.
+///
+/// Each cycle of `{name}_in` flips a toggle in the source domain,
+/// and every edge of the synchronized toggle becomes a one-cycle `{name}_out`.
+/// Pulses must be at least three destination cycles apart.
+#[derive(Debug, Clone)]
+pub struct SyncPulse {
+    pub name: String,
+    src_clk: Clock,
+    src_rst: Reset,
+    dst_clk: Clock,
+    dst_rst: Reset,
+}
+
+impl SyncPulse {
+    pub fn new(
+        name: impl ToString,
+        src_clk: impl Into<Clock>,
+        src_rst: impl Into<Reset>,
+        dst_clk: impl Into<Clock>,
+        dst_rst: impl Into<Reset>,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            src_clk: src_clk.into(),
+            src_rst: src_rst.into(),
+            dst_clk: dst_clk.into(),
+            dst_rst: dst_rst.into(),
+        }
+    }
+}
+
+impl Extension for SyncPulse {
+    fn add(self, module: Module) -> Module {
+        let [input, output, toggle, s1, s2, s3] =
+            ["in", "out", "toggle", "s1", "s2", "s3"].map(|s| format!("{}_{s}", self.name));
+        module
+            .logic(&input, 1, 1)
+            .logic(&output, 1, 1)
+            .logic(&toggle, 1, 1)
+            .add(
+                DFF::new(
+                    Stmt::assign(&toggle, "0"),
+                    Stmt::assign(&toggle, format!("{toggle} ^ {input}")),
+                )
+                .on(&self.src_clk, &self.src_rst),
+            )
+            .chain(
+                &toggle,
+                &[s1, s2.clone(), s3.clone()],
+                1,
+                &self.dst_clk,
+                &self.dst_rst,
+            )
+            .always_comb(Stmt::assign(&output, format!("{s2} ^ {s3}")))
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Req/ack handshake carrying a `bit` wide word from `src_clk` to `dst_clk`.
+///
+/// The source raises `{name}_send` with `{name}_data` while `{name}_busy` is low;
+/// the word shows up on `{name}_out` with a one-cycle `{name}_valid` in the destination domain,
+/// and `busy` falls once the acknowledge is back.
+#[derive(Debug, Clone)]
+pub struct SyncHandshake {
+    pub name: String,
+    bit: usize,
+    src_clk: Clock,
+    src_rst: Reset,
+    dst_clk: Clock,
+    dst_rst: Reset,
+}
+
+impl SyncHandshake {
+    pub fn new(
+        name: impl ToString,
+        bit: usize,
+        src_clk: impl Into<Clock>,
+        src_rst: impl Into<Reset>,
+        dst_clk: impl Into<Clock>,
+        dst_rst: impl Into<Reset>,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            bit,
+            src_clk: src_clk.into(),
+            src_rst: src_rst.into(),
+            dst_clk: dst_clk.into(),
+            dst_rst: dst_rst.into(),
+        }
+    }
+}
+
+impl Extension for SyncHandshake {
+    fn add(self, module: Module) -> Module {
+        let [send, data, busy, output, valid, hold, req, ack] =
+            ["send", "data", "busy", "out", "valid", "hold", "req", "ack"]
+                .map(|s| format!("{}_{s}", self.name));
+        let [req_s1, req_s2, ack_s1, ack_s2] =
+            ["req_s1", "req_s2", "ack_s1", "ack_s2"].map(|s| format!("{}_{s}", self.name));
+
+        // Source Domain
+        let module = module
+            .logic(&send, 1, 1)
+            .logic(&data, self.bit, 1)
+            .logic(&busy, 1, 1)
+            .logic(&hold, self.bit, 1)
+            .logic(&req, 1, 1)
+            .logic(&output, self.bit, 1)
+            .logic(&valid, 1, 1)
+            .logic(&ack, 1, 1)
+            .chain(
+                &ack,
+                &[ack_s1, ack_s2.clone()],
+                1,
+                &self.src_clk,
+                &self.src_rst,
+            )
+            .add(
+                DFF::new(
+                    Stmt::begin()
+                        .assign(&busy, "0")
+                        .assign(&hold, "0")
+                        .assign(&req, "0")
+                        .end(),
+                    Stmt::begin()
+                        .r#if(
+                            format!("{send} && !{busy}"),
+                            Stmt::begin()
+                                .assign(&hold, &data)
+                                .assign(&req, format!("!{req}"))
+                                .assign(&busy, "1")
+                                .end(),
+                        )
+                        .elif(
+                            format!("{busy} && {ack_s2} == {req}"),
+                            Stmt::assign(&busy, "0"),
+                        )
+                        .end(),
+                )
+                .on(&self.src_clk, &self.src_rst),
+            );
+
+        // Destination Domain
+        module
+            .chain(
+                &req,
+                &[req_s1, req_s2.clone()],
+                1,
+                &self.dst_clk,
+                &self.dst_rst,
+            )
+            .add(
+                DFF::new(
+                    Stmt::begin()
+                        .assign(&output, "0")
+                        .assign(&valid, "0")
+                        .assign(&ack, "0")
+                        .end(),
+                    Stmt::begin()
+                        .assign(&valid, "0")
+                        .r#if(
+                            format!("{req_s2} != {ack}"),
+                            Stmt::begin()
+                                .assign(&output, &hold)
+                                .assign(&valid, "1")
+                                .assign(&ack, &req_s2)
+                                .end(),
+                        )
+                        .end(),
+                )
+                .on(&self.dst_clk, &self.dst_rst),
+            )
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Reset synchronizer: asserts `name` as soon as `rst` is asserted and releases it on `clk`.
+///
+/// `name` has the polarity of `rst`.
+#[derive(Debug, Clone)]
+pub struct ResetSync {
+    pub name: String,
+    clk: Clock,
+    rst: Reset,
+    stages: usize,
+}
+
+impl ResetSync {
+    pub fn new(name: impl ToString, clk: impl Into<Clock>, rst: impl Into<Reset>) -> Self {
+        Self {
+            name: name.to_string(),
+            clk: clk.into(),
+            rst: rst.into(),
+            stages: 2,
+        }
+    }
+
+    /// Number of flops, 2 by default.
+    pub fn stages(mut self, stages: usize) -> Self {
+        assert!(stages >= 2, "Synchronizer needs at least two stages");
+        self.stages = stages;
+        self
+    }
+}
+
+impl Extension for ResetSync {
+    fn add(self, mut module: Module) -> Module {
+        let mut chain: Vec<String> = (1..self.stages)
+            .map(|i| format!("{}_s{i}", self.name))
+            .collect();
+        chain.push(self.name.clone());
+
+        let (asserted, released) = match self.rst.polarity {
+            Polarity::ActiveLow => ("0", "1"),
+            Polarity::ActiveHigh => ("1", "0"),
+        };
+        let mut init = Stmt::begin();
+        let mut stmt = Stmt::begin();
+        for (i, stage) in chain.iter().enumerate() {
+            module = module.logic(stage, 1, 1).attribute(stage, ASYNC_REG);
+            init = init.assign(stage, asserted);
+            stmt = stmt.assign(stage, if i == 0 { released } else { &chain[i - 1] });
+        }
+        module.add(DFF::r#async(init.end(), stmt.end()).on(&self.clk, &self.rst))
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+impl Module {
+    /// Flops `chain` of `bit` bits copying `src` one after another on `clk`/`rst`, marked `ASYNC_REG`.
+    pub(crate) fn chain(
+        mut self,
+        src: &str,
+        chain: &[String],
+        bit: usize,
+        clk: &Clock,
+        rst: &Reset,
+    ) -> Self {
+        let mut init = Stmt::begin();
+        let mut stmt = Stmt::begin();
+        let mut prev = src;
+        for stage in chain {
+            self = self.logic(stage, bit, 1).attribute(stage, ASYNC_REG);
+            init = init.assign(stage, "0");
+            stmt = stmt.assign(stage, prev);
+            prev = stage;
+        }
+        self.add(DFF::new(init.end(), stmt.end()).on(clk, rst))
+    }
+}
@@ -3,5 +3,8 @@ pub mod module;
 pub mod stmt;
 pub mod util;
 
+#[cfg(feature = "cros")]
+pub mod cros;
+
 pub mod bus;
 pub mod ext;
@@ -1,3 +1,5 @@
+#[cfg(feature = "cros")]
+use crate::cros::{Domain, Timing};
 use crate::stmt::Stmt;
 
 pub trait Extension {
@@ -12,19 +14,26 @@ pub struct Module {
     params: Vec<Param>,
     ports: Vec<Port>,
     blocks: Vec<Block>,
-    pub(crate) clock: String, // (name, pos/neg)
-    pub(crate) reset: String, // (name, pos/neg, sync/async)
+    pub(crate) clock: Clock,
+    pub(crate) reset: Reset,
+    #[cfg(feature = "cros")]
+    domains: Vec<Domain>,
 }
 
 impl Module {
-    pub fn new(name: impl ToString, clock: impl ToString, reset: impl ToString) -> Self {
+    /// Module clocked by `clock` and reset by `reset`, the default of the extensions.
+    ///
+    /// Plain names are a rising edge clock and an active-low synchronous reset.
+    pub fn new(name: impl ToString, clock: impl Into<Clock>, reset: impl Into<Reset>) -> Self {
         Self {
             name: name.to_string(),
             params: vec![],
             ports: vec![],
             blocks: vec![],
-            clock: clock.to_string(),
-            reset: reset.to_string(),
+            clock: clock.into(),
+            reset: reset.into(),
+            #[cfg(feature = "cros")]
+            domains: vec![],
         }
     }
     pub fn input(mut self, name: impl ToString, width: usize) -> Self {
@@ -71,11 +80,231 @@ impl Module {
         self
     }
 
+    /// Put a SystemVerilog attribute such as `ASYNC_REG = "TRUE"` on the port or logic `signal`.
+    pub fn attribute(mut self, signal: impl ToString, attr: impl ToString) -> Self {
+        let signal = signal.to_string();
+        let attrs = self
+            .ports
+            .iter_mut()
+            .find(|port| port.name == signal)
+            .map(|port| &mut port.attrs)
+            .or_else(|| {
+                self.blocks.iter_mut().find_map(|block| match block {
+                    Block::Logic(logic) if logic.name == signal => Some(&mut logic.attrs),
+                    _ => None,
+                })
+            })
+            .unwrap_or_else(|| panic!("Signal {signal} is not declared"));
+        attrs.push(attr.to_string());
+        self
+    }
+
     pub fn add<E: Extension>(self, ext: E) -> Self {
         ext.add(self)
     }
 }
 
+#[cfg(feature = "cros")]
+impl Module {
+    /// Declare the domain `name` clocked by `clock` and reset by `reset`.
+    pub fn domain(
+        mut self,
+        name: impl ToString,
+        clock: impl Into<Clock>,
+        reset: impl Into<Reset>,
+    ) -> Self {
+        let domain = Domain::new(name, clock, reset);
+        assert!(
+            self.get_domain(&domain.name).is_none(),
+            "Domain {} is already declared",
+            domain.name
+        );
+        self.domains.push(domain);
+        self
+    }
+    /// Clock `domain` with a period of `ns`.
+    pub fn period(mut self, domain: impl ToString, ns: f64) -> Self {
+        assert!(ns > 0.0, "Clock period must be positive");
+        self.domain_mut(&domain.to_string()).timing = Some(Timing::Period(ns));
+        self
+    }
+    /// Clock `domain` with the clock of `source` divided by `by`.
+    pub fn divided(mut self, domain: impl ToString, source: impl ToString, by: usize) -> Self {
+        let (domain, source) = (domain.to_string(), source.to_string());
+        assert!(by > 0, "Clock must be divided by a positive number");
+        assert!(
+            domain != source,
+            "Domain {domain} cannot be divided from itself"
+        );
+        assert!(
+            self.get_domain(&source).is_some(),
+            "Domain {source} is not declared"
+        );
+        let mut upstream = self.get_domain(&source);
+        while let Some(Timing::Divided { source, .. }) = upstream.and_then(|d| d.timing.as_ref()) {
+            assert!(
+                source != &domain,
+                "Clock of {domain} is divided from itself"
+            );
+            upstream = self.get_domain(source);
+        }
+        self.domain_mut(&domain).timing = Some(Timing::Divided { source, by });
+        self
+    }
+    fn domain_mut(&mut self, name: &str) -> &mut Domain {
+        self.domains
+            .iter_mut()
+            .find(|domain| domain.name == name)
+            .unwrap_or_else(|| panic!("Domain {name} is not declared"))
+    }
+    pub fn input_at(self, name: impl ToString, width: usize, domain: impl ToString) -> Self {
+        let name = name.to_string();
+        self.input(&name, width).at(name, domain)
+    }
+    pub fn output_at(self, name: impl ToString, width: usize, domain: impl ToString) -> Self {
+        let name = name.to_string();
+        self.output(&name, width).at(name, domain)
+    }
+    pub fn logic_at(
+        self,
+        name: impl ToString,
+        bit: usize,
+        len: usize,
+        domain: impl ToString,
+    ) -> Self {
+        let name = name.to_string();
+        self.logic(&name, bit, len).at(name, domain)
+    }
+    /// Tag the port or logic `signal`, declared earlier, with `domain`.
+    pub fn at(mut self, signal: impl ToString, domain: impl ToString) -> Self {
+        let (signal, domain) = (signal.to_string(), domain.to_string());
+        assert!(
+            self.get_domain(&domain).is_some(),
+            "Domain {domain} is not declared"
+        );
+        let tag = self
+            .ports
+            .iter_mut()
+            .find(|port| port.name == signal)
+            .map(|port| &mut port.domain)
+            .or_else(|| {
+                self.blocks.iter_mut().find_map(|block| match block {
+                    Block::Logic(logic) if logic.name == signal => Some(&mut logic.domain),
+                    _ => None,
+                })
+            })
+            .unwrap_or_else(|| panic!("Signal {signal} is not declared"));
+        *tag = Some(domain);
+        self
+    }
+    /// `always_ff` on the clock of `domain`.
+    pub fn always_ff_at(mut self, domain: impl ToString, stmt: Stmt) -> Self {
+        let domain = self
+            .get_domain(&domain.to_string())
+            .unwrap_or_else(|| panic!("Domain {} is not declared", domain.to_string()))
+            .clone();
+        let mut ff = AlwaysFF::new(Sens::new().clock(&domain.clock), stmt);
+        ff.domain = Some(domain.name);
+        self.blocks.push(Block::AlwaysFF(ff));
+        self
+    }
+
+    pub fn domains(&self) -> &[Domain] {
+        &self.domains
+    }
+    pub fn get_domain(&self, name: &str) -> Option<&Domain> {
+        self.domains.iter().find(|domain| domain.name == name)
+    }
+    /// Domain tagged on the port or logic `signal`.
+    pub fn domain_of(&self, signal: &str) -> Option<&Domain> {
+        self.ports
+            .iter()
+            .find(|port| port.name == signal)
+            .map(|port| &port.domain)
+            .or_else(|| {
+                self.blocks.iter().find_map(|block| match block {
+                    Block::Logic(logic) if logic.name == signal => Some(&logic.domain),
+                    _ => None,
+                })
+            })?
+            .as_ref()
+            .and_then(|name| self.get_domain(name))
+    }
+}
+
+/// `always_ff` / `always_comb` seen by the CDC checker.
+#[cfg(feature = "cros")]
+pub(crate) enum Process<'a> {
+    Seq(Option<&'a Domain>, &'a Stmt),
+    Comb(&'a Stmt),
+}
+
+/// `(name, bit, len, domain tag, attributes)` of a port or logic.
+#[cfg(feature = "cros")]
+pub(crate) type Signal<'a> = (&'a str, usize, usize, Option<&'a str>, &'a [String]);
+
+#[cfg(feature = "cros")]
+impl Module {
+    /// Ports and logics.
+    pub(crate) fn signals(&self) -> Vec<Signal<'_>> {
+        let ports = self.ports.iter().map(|port| {
+            let domain = port.domain.as_deref();
+            (
+                port.name.as_str(),
+                port.bit,
+                port.len,
+                domain,
+                port.attrs.as_slice(),
+            )
+        });
+        let logics = self.blocks.iter().filter_map(|block| match block {
+            Block::Logic(logic) => {
+                let domain = logic.domain.as_deref();
+                Some((
+                    logic.name.as_str(),
+                    logic.bit,
+                    logic.len,
+                    domain,
+                    logic.attrs.as_slice(),
+                ))
+            }
+            _ => None,
+        });
+        ports.chain(logics).collect()
+    }
+
+    /// `signal` is declared as a logic rather than a port.
+    pub(crate) fn is_logic(&self, signal: &str) -> bool {
+        self.blocks
+            .iter()
+            .any(|block| matches!(block, Block::Logic(logic) if logic.name == signal))
+    }
+
+    /// Processes with the domain of each `always_ff`,
+    /// given by `always_ff_at` or found from the clock in its sensitivity list.
+    pub(crate) fn processes(&self) -> Vec<Process<'_>> {
+        self.blocks
+            .iter()
+            .filter_map(|block| match block {
+                Block::AlwaysFF(ff) => {
+                    let domain = match &ff.domain {
+                        Some(name) => self.get_domain(name),
+                        None => ff.sens.edges.iter().find_map(|edge| match edge {
+                            Edge::Posedge(clk) | Edge::Negedge(clk) => {
+                                self.domains.iter().find(|domain| &domain.clock.name == clk)
+                            }
+                            Edge::Bothedge(_) => None,
+                        }),
+                    };
+                    Some(Process::Seq(domain, &ff.stmt))
+                }
+                Block::AlwaysComb(comb) => Some(Process::Comb(&comb.stmt)),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
 impl Module {
     pub fn verilog(&self) -> Vec<String> {
         let mut code: Vec<String> = vec![];
@@ -170,12 +399,32 @@ impl Module {
 
 // ----------------------------------------------------------------------------
 
+/// `domain = "A"` attribute of a tagged port or logic.
+#[cfg(feature = "cros")]
+macro_rules! domain_attr {
+    ($signal:expr) => {
+        $signal
+            .domain
+            .as_ref()
+            .map(|domain| format!("domain = \"{domain}\""))
+    };
+}
+#[cfg(not(feature = "cros"))]
+macro_rules! domain_attr {
+    ($signal:expr) => {
+        None
+    };
+}
+
 #[derive(Debug, Clone)]
 struct Port {
     name: String,
     direct: Direct,
     bit: usize,
     len: usize,
+    attrs: Vec<String>,
+    #[cfg(feature = "cros")]
+    domain: Option<String>,
 }
 
 impl Port {
@@ -185,6 +434,9 @@ impl Port {
             direct: Direct::In,
             bit,
             len,
+            attrs: vec![],
+            #[cfg(feature = "cros")]
+            domain: None,
         }
     }
     fn output(name: impl ToString, bit: usize, len: usize) -> Self {
@@ -193,6 +445,9 @@ impl Port {
             direct: Direct::Out,
             bit,
             len,
+            attrs: vec![],
+            #[cfg(feature = "cros")]
+            domain: None,
         }
     }
     fn inout(name: impl ToString, bit: usize, len: usize) -> Self {
@@ -201,6 +456,9 @@ impl Port {
             direct: Direct::InOut,
             bit,
             len,
+            attrs: vec![],
+            #[cfg(feature = "cros")]
+            domain: None,
         }
     }
 }
@@ -218,13 +476,17 @@ impl Port {
             format!("[{:>2}:0]", self.len - 1)
         };
         format!(
-            "{:<6} logic {}{}{}",
+            "{}{:<6} logic {}{}{}",
+            self.attribute(),
             self.direct.verilog(),
             bit,
             self.name,
             len
         )
     }
+    fn attribute(&self) -> String {
+        attribute(&self.attrs, domain_attr!(self))
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -320,6 +582,9 @@ struct Logic {
     name: String,
     bit: usize,
     len: usize,
+    attrs: Vec<String>,
+    #[cfg(feature = "cros")]
+    domain: Option<String>,
 }
 
 impl Logic {
@@ -328,6 +593,9 @@ impl Logic {
             name: name.to_string(),
             bit,
             len,
+            attrs: vec![],
+            #[cfg(feature = "cros")]
+            domain: None,
         }
     }
 }
@@ -344,7 +612,26 @@ impl Logic {
         } else {
             format!("[{:>2}:0]", self.len - 1)
         };
-        vec![format!("logic {}{}{};", bit, self.name, len)]
+        vec![format!(
+            "{}logic {}{}{};",
+            self.attribute(),
+            bit,
+            self.name,
+            len
+        )]
+    }
+    fn attribute(&self) -> String {
+        attribute(&self.attrs, domain_attr!(self))
+    }
+}
+
+/// `(* ASYNC_REG = "TRUE", domain = "A" *)` in front of a declaration.
+fn attribute(attrs: &[String], domain: Option<String>) -> String {
+    let attrs: Vec<String> = attrs.iter().cloned().chain(domain).collect();
+    if attrs.is_empty() {
+        String::new()
+    } else {
+        format!("(* {} *) ", attrs.join(", "))
     }
 }
 
@@ -416,11 +703,18 @@ fn test_instant() {
 pub struct AlwaysFF {
     sens: Sens,
     stmt: Stmt,
+    #[cfg(feature = "cros")]
+    domain: Option<String>,
 }
 
 impl AlwaysFF {
     pub fn new(sens: Sens, stmt: Stmt) -> Self {
-        Self { sens, stmt }
+        Self {
+            sens,
+            stmt,
+            #[cfg(feature = "cros")]
+            domain: None,
+        }
     }
 }
 
@@ -454,6 +748,20 @@ impl Sens {
         self.edges.push(Edge::Bothedge(wire.to_string()));
         self
     }
+    /// Active edge of `clock`.
+    pub fn clock(self, clock: &Clock) -> Self {
+        match clock.edge {
+            ClockEdge::Pos => self.posedge(&clock.name),
+            ClockEdge::Neg => self.negedge(&clock.name),
+        }
+    }
+    /// Asserting edge of `reset`, for asynchronous resets.
+    pub fn reset(self, reset: &Reset) -> Self {
+        match reset.polarity {
+            Polarity::ActiveLow => self.negedge(&reset.name),
+            Polarity::ActiveHigh => self.posedge(&reset.name),
+        }
+    }
 }
 
 impl Sens {
@@ -485,6 +793,148 @@ impl Edge {
 
 // ----------------------------------------------------------------------------
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClockEdge {
+    Pos,
+    Neg,
+}
+
+/// Clock signal and its active edge.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Clock {
+    pub name: String,
+    pub edge: ClockEdge,
+}
+
+impl Clock {
+    pub fn posedge(name: impl ToString) -> Self {
+        Self {
+            name: name.to_string(),
+            edge: ClockEdge::Pos,
+        }
+    }
+    pub fn negedge(name: impl ToString) -> Self {
+        Self {
+            name: name.to_string(),
+            edge: ClockEdge::Neg,
+        }
+    }
+}
+
+impl From<&str> for Clock {
+    fn from(name: &str) -> Self {
+        Clock::posedge(name)
+    }
+}
+
+impl From<String> for Clock {
+    fn from(name: String) -> Self {
+        Clock::posedge(name)
+    }
+}
+
+impl From<&String> for Clock {
+    fn from(name: &String) -> Self {
+        Clock::posedge(name)
+    }
+}
+
+impl From<&Clock> for Clock {
+    fn from(clock: &Clock) -> Self {
+        clock.clone()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Polarity {
+    ActiveLow,
+    ActiveHigh,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResetStyle {
+    Sync,
+    Async,
+    /// No reset: flops keep only their `stmt`.
+    None,
+}
+
+/// Reset signal, its polarity and how flops use it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reset {
+    pub name: String,
+    pub polarity: Polarity,
+    pub style: ResetStyle,
+}
+
+impl Reset {
+    /// Active-low synchronous reset.
+    pub fn low(name: impl ToString) -> Self {
+        Self {
+            name: name.to_string(),
+            polarity: Polarity::ActiveLow,
+            style: ResetStyle::Sync,
+        }
+    }
+    /// Active-high synchronous reset.
+    pub fn high(name: impl ToString) -> Self {
+        Self {
+            name: name.to_string(),
+            polarity: Polarity::ActiveHigh,
+            style: ResetStyle::Sync,
+        }
+    }
+    /// No reset at all.
+    pub fn none() -> Self {
+        Self {
+            name: String::new(),
+            polarity: Polarity::ActiveLow,
+            style: ResetStyle::None,
+        }
+    }
+    pub fn sync(mut self) -> Self {
+        self.style = ResetStyle::Sync;
+        self
+    }
+    pub fn r#async(mut self) -> Self {
+        self.style = ResetStyle::Async;
+        self
+    }
+    /// Condition true while the reset is asserted: `!rstn` / `rst`.
+    pub fn active(&self) -> String {
+        match self.polarity {
+            Polarity::ActiveLow => format!("!{}", self.name),
+            Polarity::ActiveHigh => self.name.clone(),
+        }
+    }
+}
+
+impl From<&str> for Reset {
+    fn from(name: &str) -> Self {
+        Reset::low(name)
+    }
+}
+
+impl From<String> for Reset {
+    fn from(name: String) -> Self {
+        Reset::low(name)
+    }
+}
+
+impl From<&String> for Reset {
+    fn from(name: &String) -> Self {
+        Reset::low(name)
+    }
+}
+
+impl From<&Reset> for Reset {
+    fn from(reset: &Reset) -> Self {
+        reset.clone()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 #[derive(Debug, Clone)]
 pub struct AlwaysComb {
     stmt: Stmt,
@@ -56,6 +56,61 @@ impl Stmt {
     }
 }
 
+#[cfg(feature = "cros")]
+impl Stmt {
+    /// Every assignment as `(conditions, var, val)`, with the `if` / `case` conditions it is under.
+    pub(crate) fn assigns(&self) -> Vec<(Vec<&str>, &str, &str)> {
+        let mut assigns = vec![];
+        self.collect(&mut vec![], &mut assigns);
+        assigns
+    }
+
+    fn collect<'a>(
+        &'a self,
+        conds: &mut Vec<&'a str>,
+        out: &mut Vec<(Vec<&'a str>, &'a str, &'a str)>,
+    ) {
+        let mut under = |conds: &mut Vec<&'a str>, branch: &[&'a str], stmt: &'a Stmt| {
+            let depth = conds.len();
+            conds.extend(branch);
+            stmt.collect(conds, out);
+            conds.truncate(depth);
+        };
+        match self {
+            Stmt::Empty => {}
+            Stmt::Assign(assign) => out.push((conds.clone(), &assign.var, &assign.val)),
+            Stmt::Block(block) => {
+                // Conditions of the if / else if chain so far
+                let mut chain = vec![];
+                for stmt in &block.body {
+                    match stmt {
+                        Stmt::If(cond, stmt) => {
+                            chain = vec![cond.as_str()];
+                            under(conds, &chain, stmt);
+                        }
+                        Stmt::ElIf(cond, stmt) => {
+                            chain.push(cond);
+                            under(conds, &chain, stmt);
+                        }
+                        Stmt::Else(stmt) => under(conds, &chain, stmt),
+                        stmt => under(conds, &[], stmt),
+                    }
+                }
+            }
+            Stmt::If(cond, stmt) | Stmt::ElIf(cond, stmt) => under(conds, &[cond], stmt),
+            Stmt::Else(stmt) => under(conds, &[], stmt),
+            Stmt::Case(case) => {
+                for (label, stmt) in &case.case {
+                    under(conds, &[&case.var, label], stmt);
+                }
+                if let Some(stmt) = &case.default {
+                    under(conds, &[&case.var], stmt);
+                }
+            }
+        }
+    }
+}
+
 // ----------------------------------------------------------------------------
 
 #[derive(Debug, Clone)]
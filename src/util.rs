@@ -7,6 +7,14 @@ pub fn clog2(n: usize) -> Option<usize> {
     }
 }
 
+pub(crate) fn mask(bit: usize) -> usize {
+    if bit >= usize::BITS as usize {
+        usize::MAX
+    } else {
+        (1 << bit) - 1
+    }
+}
+
 pub(crate) fn sel(n: usize, size: usize) -> String {
     if size == 1 {
         format!("")
@@ -0,0 +1,22 @@
+use ruverta::{
+    bus::{AHBLiteSlave, RegList},
+    mod_test,
+    module::Module,
+};
+
+mod_test!(
+    ahb_lite_slave,
+    Module::new("ahb_lite_slave", "clk", "rstn")
+        .input("clk", 1)
+        .input("rstn", 1)
+        .add(AHBLiteSlave::new(
+            Some("hbus"),
+            "clk",
+            "rstn",
+            RegList::new()
+                .read_write("csr_rw", 32, 4)
+                .read_only("csr_ro", 8, 1)
+                .trigger("csr_tw")
+                .allocate_greedy(32, 8),
+        ))
+);
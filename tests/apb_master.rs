@@ -0,0 +1,9 @@
+use ruverta::{bus::APBMaster, mod_test, module::Module};
+
+mod_test!(
+    apb_master,
+    Module::new("apb_master", "clk", "rstn")
+        .input("clk", 1)
+        .input("rstn", 1)
+        .add(APBMaster::new(Some("pbus"), "clk", "rstn", 16, 32))
+);
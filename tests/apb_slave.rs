@@ -0,0 +1,44 @@
+use ruverta::{
+    bus::{APBSlave, RegList},
+    mod_test,
+    module::{Module, Reset},
+};
+
+mod_test!(
+    apb_slave,
+    Module::new("apb_slave", "clk", "rstn")
+        .input("clk", 1)
+        .input("rstn", 1)
+        .add(
+            APBSlave::new(
+                Some("pbus"),
+                "clk",
+                "rstn",
+                RegList::new()
+                    .read_write("csr_rw", 16, 4)
+                    .read_only("csr_ro", 8, 1)
+                    .trigger("csr_tw")
+                    .allocate_greedy(32, 8),
+            )
+            .wait(2)
+        )
+);
+
+mod_test!(
+    apb_slave_async_reset,
+    Module::new(
+        "apb_slave_async_reset",
+        "pclk",
+        Reset::low("presetn").r#async()
+    )
+    .input("pclk", 1)
+    .input("presetn", 1)
+    .add(APBSlave::new(
+        None,
+        "pclk",
+        Reset::low("presetn").r#async(),
+        RegList::new()
+            .read_write("csr_rw", 16, 1)
+            .allocate_greedy(32, 8),
+    ))
+);
@@ -0,0 +1,44 @@
+use ruverta::{
+    bus::{AXI4Master, AXI4Slave, RegList},
+    mod_test,
+    module::Module,
+};
+
+mod_test!(
+    axi4_slave,
+    Module::new("axi4_slave", "clk", "rstn")
+        .input("clk", 1)
+        .input("rstn", 1)
+        .add(
+            AXI4Slave::new(
+                Some("sbus"),
+                "clk",
+                "rstn",
+                RegList::new()
+                    .read_write("ctrl", 32, 1)
+                    .base(0)
+                    .read_write("lut", 32, 4)
+                    .base(4)
+                    .read_only("status", 8, 1)
+                    .base(8)
+                    .allocate_greedy(32, 8),
+            )
+            .id_bit(2)
+        )
+);
+
+mod_test!(
+    axi4_memory,
+    Module::new("axi4_memory", "clk", "rstn")
+        .input("clk", 1)
+        .input("rstn", 1)
+        .add(AXI4Slave::memory(Some("sbus"), "clk", "rstn", 12, 32, 1024).outstanding(8))
+);
+
+mod_test!(
+    axi4_master,
+    Module::new("axi4_master", "clk", "rstn")
+        .input("clk", 1)
+        .input("rstn", 1)
+        .add(AXI4Master::new(Some("mbus"), "clk", "rstn", 32, 64))
+);
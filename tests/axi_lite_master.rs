@@ -0,0 +1,9 @@
+use ruverta::{bus::AXILiteMaster, mod_test, module::Module};
+
+mod_test!(
+    axi_lite_master,
+    Module::new("axi_lite_master", "clk", "rstn")
+        .input("clk", 1)
+        .input("rstn", 1)
+        .add(AXILiteMaster::new(Some("mbus"), "clk", "rstn", 32, 32))
+);
@@ -0,0 +1,31 @@
+use ruverta::{bus::Bridge, mod_test, module::Module};
+
+mod_test!(
+    axi_lite_to_apb,
+    Module::new("axi_lite_to_apb", "clk", "rstn")
+        .input("clk", 1)
+        .input("rstn", 1)
+        .add(Bridge::axi_lite_to_apb(
+            Some("cbus"),
+            Some("pbus"),
+            "clk",
+            "rstn",
+            12,
+            32
+        ))
+);
+
+mod_test!(
+    axi_lite_to_wishbone,
+    Module::new("axi_lite_to_wishbone", "clk", "rstn")
+        .input("clk", 1)
+        .input("rstn", 1)
+        .add(Bridge::axi_lite_to_wishbone(
+            Some("cbus"),
+            Some("wbus"),
+            "clk",
+            "rstn",
+            12,
+            32
+        ))
+);
@@ -0,0 +1,50 @@
+#ifndef UART_H
+#define UART_H
+
+/* ctrl : read-write */
+#define UART_CTRL_OFFSET 0x0
+#define UART_CTRL_MASK 0xffu
+#define UART_CTRL_RESET 0x81u
+#define UART_CTRL_ENABLE_SHIFT 0
+#define UART_CTRL_ENABLE_MASK 0x1u
+#define UART_CTRL_MODE_SHIFT 4
+#define UART_CTRL_MODE_MASK 0x70u
+
+/* status : read-only */
+#define UART_STATUS_OFFSET 0x4
+#define UART_STATUS_MASK 0xffu
+#define UART_STATUS_RX_VALID_SHIFT 0
+#define UART_STATUS_RX_VALID_MASK 0x1u
+
+/* start : trigger */
+#define UART_START_OFFSET 0x8
+#define UART_START_MASK 0x1u
+#define UART_START_RESET 0x0u
+
+/* lut : read-write */
+#define UART_LUT_OFFSET(i) (0x40 + (i) * 0x8)
+#define UART_LUT_COUNT 4
+#define UART_LUT_MASK 0xffffu
+#define UART_LUT_RESET 0x0u
+
+/* int_status : read, write 1 to clear */
+#define UART_INT_STATUS_OFFSET 0x60
+#define UART_INT_STATUS_MASK 0x1u
+#define UART_INT_STATUS_RESET 0x0u
+#define UART_INT_STATUS_RX_VALID_SHIFT 0
+#define UART_INT_STATUS_RX_VALID_MASK 0x1u
+
+/* int_enable : read-write */
+#define UART_INT_ENABLE_OFFSET 0x64
+#define UART_INT_ENABLE_MASK 0x1u
+#define UART_INT_ENABLE_RESET 0x0u
+#define UART_INT_ENABLE_RX_VALID_SHIFT 0
+#define UART_INT_ENABLE_RX_VALID_MASK 0x1u
+
+/* int_test : write 1 to set */
+#define UART_INT_TEST_OFFSET 0x68
+#define UART_INT_TEST_MASK 0x1u
+#define UART_INT_TEST_RX_VALID_SHIFT 0
+#define UART_INT_TEST_RX_VALID_MASK 0x1u
+
+#endif /* UART_H */
\ No newline at end of file
@@ -0,0 +1,66 @@
+use ruverta::bus::{MemMap, RegList};
+use std::{fs, path::PathBuf};
+
+fn mem() -> MemMap {
+    RegList::new()
+        .read_write("ctrl", 8, 1)
+        .reset(0x81)
+        .field("enable", 0, 1)
+        .field("mode", 4, 3)
+        .read_only("status", 8, 1)
+        .field("rx_valid", 0, 1)
+        .interrupt()
+        .trigger("start")
+        .read_write("lut", 16, 4)
+        .base(0x10)
+        .stride(2)
+        .allocate_greedy(32, 8)
+}
+
+#[test]
+fn c_header() {
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push("tests/c/c_header.h");
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, mem().c_header("uart").join("\n")).unwrap();
+}
+
+/// Value of `#define name`, evaluating `(base + (i) * step)` for array offsets.
+fn define(header: &[String], name: &str, i: usize) -> usize {
+    let prefix = format!("#define {name} ");
+    let expr = header
+        .iter()
+        .find_map(|line| line.strip_prefix(&prefix))
+        .unwrap_or_else(|| panic!("{name} is not defined"));
+    let num = |s: &str| {
+        let s = s.trim_matches(|c| "() ".contains(c)).trim_end_matches('u');
+        match s.strip_prefix("0x") {
+            Some(hex) => usize::from_str_radix(hex, 16).unwrap(),
+            None if s == "i" => i,
+            None => s.parse().unwrap(),
+        }
+    };
+    expr.split(" + ")
+        .map(|term| term.split(" * ").map(num).product::<usize>())
+        .sum()
+}
+
+#[test]
+fn c_header_values() {
+    let header = mem().c_header("uart");
+    assert_eq!(define(&header, "UART_CTRL_OFFSET", 0), 0x0);
+    assert_eq!(define(&header, "UART_CTRL_RESET", 0), 0x81);
+    assert_eq!(define(&header, "UART_CTRL_MODE_MASK", 0), 0x70);
+
+    // `lut[i]` at register index 0x10 + 2 * i, 4 bytes per register
+    for i in 0..4 {
+        assert_eq!(define(&header, "UART_LUT_OFFSET(i)", i), (0x10 + 2 * i) * 4);
+    }
+    assert_eq!(define(&header, "UART_LUT_COUNT", 0), 4);
+
+    // Interrupt registers follow the array, with `rx_valid` as source 0
+    assert_eq!(define(&header, "UART_INT_STATUS_OFFSET", 0), 0x18 * 4);
+    assert_eq!(define(&header, "UART_INT_ENABLE_OFFSET", 0), 0x19 * 4);
+    assert_eq!(define(&header, "UART_INT_TEST_OFFSET", 0), 0x1a * 4);
+    assert_eq!(define(&header, "UART_INT_STATUS_RX_VALID_MASK", 0), 0x1);
+}
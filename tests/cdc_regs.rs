@@ -0,0 +1,25 @@
+use ruverta::{
+    bus::{AXILiteSlave, RegList},
+    mod_test,
+    module::Module,
+};
+
+mod_test!(
+    cdc_regs,
+    Module::new("cdc_regs", "core_clk", "core_rstn")
+        .input("bus_clk", 1)
+        .input("bus_rstn", 1)
+        .input("core_clk", 1)
+        .input("core_rstn", 1)
+        .add(AXILiteSlave::new(
+            Some("cbus"),
+            "bus_clk",
+            "bus_rstn",
+            RegList::new()
+                .read_write("csr_rw", 8, 2)
+                .read_only("csr_ro", 8, 1)
+                .trigger("csr_tw")
+                .allocate_greedy(32, 8)
+                .cdc("core_clk", "core_rstn"),
+        ))
+);
@@ -0,0 +1,326 @@
+#[cfg(feature = "cros")]
+use ruverta::{
+    bus::{AXILiteSlave, RegList},
+    ext::{AsyncFIFO, ResetSync, SyncBit, SyncHandshake, SyncPulse},
+    mod_test,
+    module::{Module, Sens},
+    stmt::Stmt,
+};
+
+#[cfg(feature = "cros")]
+mod_test!(
+    cros_domain,
+    Module::new("cros_domain", "clk_a", "rstn_a")
+        .domain("A", "clk_a", "rstn_a")
+        .domain("B", "clk_b", "rstn_b")
+        .input("clk_a", 1)
+        .input("rstn_a", 1)
+        .input("clk_b", 1)
+        .input("rstn_b", 1)
+        .input_at("a", 8, "A")
+        .output_at("b", 8, "B")
+        .logic_at("a_q", 8, 1, "A")
+        .logic("b_q", 8, 1)
+        .at("b_q", "B")
+        .always_ff_at(
+            "A",
+            Stmt::begin()
+                .r#if("!rstn_a", Stmt::assign("a_q", "0"))
+                .r#else(Stmt::assign("a_q", "a"))
+                .end()
+        )
+        .always_ff_at(
+            "B",
+            Stmt::begin()
+                .r#if("!rstn_b", Stmt::assign("b_q", "0"))
+                .r#else(Stmt::assign("b_q", "a_q"))
+                .end()
+        )
+        .always_comb(Stmt::assign("b", "b_q"))
+);
+
+#[cfg(feature = "cros")]
+#[test]
+fn cros_domain_of() {
+    let module = Module::new("cros_domain_of", "clk", "rstn")
+        .domain("A", "clk", "rstn")
+        .input_at("a", 1, "A")
+        .logic("x", 1, 1);
+    assert_eq!(
+        module.domain_of("a").map(|d| d.clock.name.as_str()),
+        Some("clk")
+    );
+    assert!(module.domain_of("x").is_none());
+}
+
+#[cfg(feature = "cros")]
+#[test]
+fn cros_check_unsynchronized() {
+    let module = Module::new("cros_unsync", "clk_a", "rstn_a")
+        .domain("A", "clk_a", "rstn_a")
+        .domain("B", "clk_b", "rstn_b")
+        .input_at("a", 8, "A")
+        .logic("a_q", 8, 1)
+        .logic("a_inv", 8, 1)
+        .logic("b_q", 8, 1)
+        .always_ff_at("A", Stmt::assign("a_q", "a"))
+        .always_comb(Stmt::assign("a_inv", "~a_q"))
+        .always_ff_at("B", Stmt::assign("b_q", "a_inv + 1"));
+    let violations = module.check_cdc().unwrap_err();
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].to_string(), "A -> B: a_q -> a_inv -> b_q");
+}
+
+#[cfg(feature = "cros")]
+#[test]
+fn cros_check_sync2() {
+    let module = Module::new("cros_sync2", "clk_a", "rstn_a")
+        .domain("A", "clk_a", "rstn_a")
+        .domain("B", "clk_b", "rstn_b")
+        .input_at("a", 1, "A")
+        .logic("a_s1", 1, 1)
+        .logic("a_s2", 1, 1)
+        .logic("b", 1, 1)
+        .always_ff_at(
+            "B",
+            Stmt::begin()
+                .assign("a_s1", "a")
+                .assign("a_s2", "a_s1")
+                .end(),
+        )
+        .always_ff_at("B", Stmt::assign("b", "a_s2 && !b"));
+    assert_eq!(module.check_cdc(), Ok(()));
+
+    // A single flop is not a synchronizer
+    let single = Module::new("cros_sync1", "clk_b", "rstn_b")
+        .domain("A", "clk_a", "rstn_a")
+        .domain("B", "clk_b", "rstn_b")
+        .input_at("a", 1, "A")
+        .logic("a_s1", 1, 1)
+        .always_ff_at("B", Stmt::assign("a_s1", "a"));
+    assert!(single.check_cdc().is_err());
+
+    // The first stage must not feed any other logic
+    let module = module
+        .always_comb(Stmt::assign("b_early", "a_s1"))
+        .logic("b_early", 1, 1);
+    assert!(module.check_cdc().is_err());
+}
+
+#[cfg(feature = "cros")]
+#[test]
+fn cros_check_sync2_bus() {
+    let counter = |value: &str| {
+        Module::new("cros_sync2_bus", "clk_a", "rstn_a")
+            .domain("A", "clk_a", "rstn_a")
+            .domain("B", "clk_b", "rstn_b")
+            .period("A", 10.0)
+            .period("B", 8.0)
+            .logic("cnt", 4, 1)
+            .logic("cnt_next", 4, 1)
+            .logic("cnt_s1", 4, 1)
+            .logic("cnt_s2", 4, 1)
+            .always_comb(Stmt::assign("cnt_next", "cnt + 1"))
+            .always_ff_at("A", Stmt::assign("cnt", value))
+            .always_ff_at(
+                "B",
+                Stmt::begin()
+                    .assign("cnt_s1", "cnt")
+                    .assign("cnt_s2", "cnt_s1")
+                    .end(),
+            )
+    };
+
+    // A binary counter can be caught between two values
+    let binary = counter("cnt_next");
+    let violations = binary.check_cdc().unwrap_err();
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].to_string(), "A -> B: cnt -> cnt_s1");
+    assert!(!binary.sdc().iter().any(|line| line.contains("cnt_s1")));
+
+    // A gray code changes one bit at a time
+    let gray = counter("(cnt_next >> 1) ^ cnt_next");
+    assert_eq!(gray.check_cdc(), Ok(()));
+    assert!(gray.sdc().contains(
+        &"set_max_delay -datapath_only -from [get_clocks A] -to [get_cells cnt_s1_reg*] 8.000"
+            .to_string()
+    ));
+
+    // A value held until the synchronized acknowledge returns
+    let held = Module::new("cros_sync2_held", "clk_a", "rstn_a")
+        .domain("A", "clk_a", "rstn_a")
+        .domain("B", "clk_b", "rstn_b")
+        .input_at("data", 8, "A")
+        .logic("hold", 8, 1)
+        .logic("req", 1, 1)
+        .logic("ack", 1, 1)
+        .logic("ack_s1", 1, 1)
+        .logic("ack_s2", 1, 1)
+        .logic("req_s1", 1, 1)
+        .logic("req_s2", 1, 1)
+        .logic("hold_s1", 8, 1)
+        .logic("hold_s2", 8, 1)
+        .always_ff_at(
+            "A",
+            Stmt::begin()
+                .assign("ack_s1", "ack")
+                .assign("ack_s2", "ack_s1")
+                .r#if(
+                    "ack_s2 == req",
+                    Stmt::begin()
+                        .assign("hold", "data")
+                        .assign("req", "!req")
+                        .end(),
+                )
+                .end(),
+        )
+        .always_ff_at(
+            "B",
+            Stmt::begin()
+                .assign("req_s1", "req")
+                .assign("req_s2", "req_s1")
+                .assign("hold_s1", "hold")
+                .assign("hold_s2", "hold_s1")
+                .assign("ack", "req_s2")
+                .end(),
+        );
+    assert_eq!(held.check_cdc(), Ok(()));
+}
+
+#[cfg(feature = "cros")]
+#[test]
+fn cros_check_same_clock() {
+    let module = Module::new("cros_same_clock", "clk", "rstn")
+        .domain("A", "clk", "rstn")
+        .domain("B", "clk", "rstn_b")
+        .period("A", 10.0)
+        .period("B", 10.0)
+        .input_at("a", 8, "A")
+        .logic_at("b", 8, 1, "B")
+        .logic_at("c", 8, 1, "B")
+        .always_ff_at("B", Stmt::assign("b", "a"))
+        .always_ff(Sens::new().posedge("clk"), Stmt::assign("c", "b"));
+    assert_eq!(module.check_cdc(), Ok(()));
+    assert_eq!(
+        module.sdc(),
+        vec!["create_clock -name A -period 10.000 [get_ports clk]"]
+    );
+}
+
+#[cfg(feature = "cros")]
+#[test]
+fn cros_check_memory() {
+    // Only the buffer of an async FIFO may be read across clocks
+    let module = Module::new("cros_memory", "clk_b", "rstn_b")
+        .domain("A", "clk_a", "rstn_a")
+        .domain("B", "clk_b", "rstn_b")
+        .logic_at("mem", 8, 4, "A")
+        .logic_at("ptr", 2, 1, "B")
+        .logic("b", 8, 1)
+        .always_ff_at("B", Stmt::assign("b", "mem[ptr]"));
+    let violations = module.check_cdc().unwrap_err();
+    assert_eq!(violations[0].to_string(), "A -> B: mem -> b");
+}
+
+#[cfg(feature = "cros")]
+#[test]
+fn cros_check_async_fifo() {
+    for fwft in [false, true] {
+        let fifo = AsyncFIFO::new("cross", 16, 16, "wclk", "wrstn", "rclk", "rrstn");
+        let module = Module::new("cros_async_fifo", "wclk", "wrstn")
+            .domain("W", "wclk", "wrstn")
+            .domain("R", "rclk", "rrstn")
+            .add(if fwft { fifo.fwft() } else { fifo });
+        assert_eq!(module.check_cdc(), Ok(()));
+    }
+}
+
+#[cfg(feature = "cros")]
+#[test]
+fn cros_check_cdc_regs() {
+    let module = Module::new("cros_cdc_regs", "core_clk", "core_rstn")
+        .domain("bus", "bus_clk", "bus_rstn")
+        .domain("core", "core_clk", "core_rstn")
+        .add(AXILiteSlave::new(
+            Some("cbus"),
+            "bus_clk",
+            "bus_rstn",
+            RegList::new()
+                .read_write("csr_rw", 8, 2)
+                .read_only("csr_ro", 8, 1)
+                .trigger("csr_tw")
+                .allocate_greedy(32, 8)
+                .cdc("core_clk", "core_rstn"),
+        ));
+    assert_eq!(module.check_cdc(), Ok(()));
+}
+
+#[cfg(feature = "cros")]
+#[test]
+fn cros_check_sync() {
+    let module = Module::new("cros_sync", "clk_a", "rstn_a")
+        .domain("A", "clk_a", "rstn_a")
+        .domain("B", "clk_b", "rstn_b")
+        .input_at("a", 1, "A")
+        .add(SyncBit::new("flag", "clk_b", "rstn_b"))
+        .always_comb(Stmt::assign("flag_in", "a"))
+        .add(SyncPulse::new("kick", "clk_a", "rstn_a", "clk_b", "rstn_b"))
+        .add(SyncHandshake::new(
+            "cfg", 16, "clk_a", "rstn_a", "clk_b", "rstn_b",
+        ))
+        .add(ResetSync::new("rstn_b_sync", "clk_b", "rstn_b"));
+    assert_eq!(module.check_cdc(), Ok(()));
+}
+
+#[cfg(feature = "cros")]
+#[test]
+fn cros_sdc() {
+    let module = Module::new("cros_sdc", "clk_a", "rstn_a")
+        .domain("A", "clk_a", "rstn_a")
+        .domain("B", "clk_b", "rstn_b")
+        .domain("D", "clk_d", "rstn_a")
+        .period("A", 10.0)
+        .period("B", 8.0)
+        .divided("D", "A", 2)
+        .input("clk_a", 1)
+        .input("rstn_a", 1)
+        .input("clk_b", 1)
+        .input("rstn_b", 1)
+        .logic("clk_d", 1, 1)
+        .input_at("a", 1, "A")
+        .add(SyncBit::new("flag", "clk_b", "rstn_b"))
+        .always_comb(Stmt::assign("flag_in", "a"))
+        .add(AsyncFIFO::new(
+            "cross", 16, 4, "clk_a", "rstn_a", "clk_b", "rstn_b",
+        ));
+    assert_eq!(
+        module.sdc(),
+        vec![
+            "create_clock -name A -period 10.000 [get_ports clk_a]",
+            "create_clock -name B -period 8.000 [get_ports clk_b]",
+            "create_generated_clock -name D -source [get_ports clk_a] -divide_by 2 [get_nets clk_d]",
+            "set_clock_groups -asynchronous -group [get_clocks {A D}] -group [get_clocks {B}]",
+            "set_false_path -to [get_cells flag_s1_reg*]",
+            "set_max_delay -datapath_only -from [get_clocks B] -to [get_cells cross_wq1_rgray_reg*] 8.000",
+            "set_max_delay -datapath_only -from [get_clocks A] -to [get_cells cross_rq1_wgray_reg*] 8.000",
+        ]
+    );
+
+    // Gray pointers into a domain without a period keep their skew bound out of a false path
+    let module = Module::new("cros_sdc_unperioded", "clk_a", "rstn_a")
+        .domain("A", "clk_a", "rstn_a")
+        .domain("E", "clk_e", "rstn_e")
+        .period("A", 10.0)
+        .input("clk_a", 1)
+        .input("rstn_a", 1)
+        .input("clk_e", 1)
+        .input("rstn_e", 1)
+        .add(AsyncFIFO::new(
+            "slow", 8, 4, "clk_a", "rstn_a", "clk_e", "rstn_e",
+        ));
+    assert_eq!(module.check_cdc(), Ok(()));
+    assert_eq!(
+        module.sdc(),
+        vec!["create_clock -name A -period 10.000 [get_ports clk_a]"]
+    );
+}
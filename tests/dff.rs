@@ -1,4 +1,9 @@
-use ruverta::{ext::DFF, mod_test, module::Module, stmt::Stmt};
+use ruverta::{
+    ext::DFF,
+    mod_test,
+    module::{Clock, Module, Reset},
+    stmt::Stmt,
+};
 
 mod_test!(
     dff,
@@ -14,3 +19,42 @@ mod_test!(
             Stmt::begin().assign("out", "in0 + in1").end(),
         ))
 );
+
+mod_test!(
+    dff_domain,
+    Module::new("dff_domain", "clk", "rstn")
+        .input("clk", 1)
+        .input("rstn", 1)
+        .input("clk_n", 1)
+        .input("rst", 1)
+        .input("in0", 8)
+        .output("out0", 8)
+        .output("out1", 8)
+        .add(
+            DFF::new(Stmt::assign("out0", "0"), Stmt::assign("out0", "in0"))
+                .on(Clock::negedge("clk_n"), Reset::high("rst").r#async())
+        )
+        .add(
+            DFF::new(Stmt::assign("out1", "0"), Stmt::assign("out1", "out0"))
+                .on("clk", Reset::none())
+        )
+);
+
+mod_test!(
+    dff_enable,
+    Module::new("dff_enable", "clk", "rstn")
+        .input("clk", 1)
+        .input("rstn", 1)
+        .input("en", 1)
+        .input("in0", 8)
+        .output("count", 8)
+        .output("data", 8)
+        .add(
+            DFF::new(
+                Stmt::assign("count", "0"),
+                Stmt::assign("count", "count + 1")
+            )
+            .enable("en")
+        )
+        .add(DFF::no_reset(Stmt::assign("data", "in0")).enable("en"))
+);
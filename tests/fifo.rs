@@ -1,6 +1,40 @@
-use ruverta::{ext::FIFO, mod_test, module::Module};
+use ruverta::{
+    ext::{AsyncFIFO, FIFO},
+    mod_test,
+    module::Module,
+};
 
 mod_test!(
     fifo,
     Module::new("fifo", "clk", "rstn").add(FIFO::new("rx", 8, 32))
 );
+
+mod_test!(
+    fifo_fwft,
+    Module::new("fifo_fwft", "clk", "rstn").add(
+        FIFO::new("rx", 8, 24)
+            .almost_full(20)
+            .almost_empty(4)
+            .fwft()
+    )
+);
+
+mod_test!(
+    fifo_ports,
+    Module::new("fifo_ports", "clk", "rstn")
+        .input("clk", 1)
+        .input("rstn", 1)
+        .add(FIFO::new("tx", 8, 16).almost_full(12).ports())
+);
+
+mod_test!(
+    async_fifo,
+    Module::new("async_fifo", "clk", "rstn")
+        .input("wclk", 1)
+        .input("wrstn", 1)
+        .input("rclk", 1)
+        .input("rrstn", 1)
+        .add(AsyncFIFO::new(
+            "cross", 16, 16, "wclk", "wrstn", "rclk", "rrstn"
+        ))
+);
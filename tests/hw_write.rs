@@ -0,0 +1,24 @@
+use ruverta::{
+    bus::{AXILiteSlave, RegList},
+    mod_test,
+    module::Module,
+};
+
+mod_test!(
+    hw_write,
+    Module::new("hw_write", "clk", "rstn")
+        .input("clk", 1)
+        .input("rstn", 1)
+        .add(AXILiteSlave::new(
+            Some("cbus"),
+            "clk",
+            "rstn",
+            RegList::new()
+                .read_write("ctrl", 8, 1)
+                .read_write("flag", 1, 2)
+                .hw_write()
+                .read_only("count", 16, 1)
+                .hw_write()
+                .allocate_greedy(32, 8),
+        ))
+);
@@ -0,0 +1,56 @@
+use ruverta::{
+    bus::{Interconnect, MemMap, RegList},
+    mod_test,
+    module::Module,
+};
+
+fn uart() -> MemMap {
+    RegList::new()
+        .read_write("ctrl", 32, 1)
+        .write_only("tx", 8, 1)
+        .read_only("rx", 8, 1)
+        .allocate_greedy(32, 4)
+}
+
+fn gpio() -> MemMap {
+    RegList::new()
+        .read_write("out", 32, 1)
+        .read_only("in", 32, 1)
+        .allocate_greedy(32, 8)
+}
+
+mod_test!(
+    axi_lite_interconnect,
+    Module::new("axi_lite_interconnect", "clk", "rstn")
+        .input("clk", 1)
+        .input("rstn", 1)
+        .add(
+            Interconnect::axi_lite(Some("cpu"), "clk", "rstn", 12, 32)
+                .slave("uart", 0x010, &uart())
+                .slave("gpio", 0x100, &gpio())
+        )
+);
+
+mod_test!(
+    apb_interconnect,
+    Module::new("apb_interconnect", "clk", "rstn")
+        .input("clk", 1)
+        .input("rstn", 1)
+        .add(
+            Interconnect::apb(Some("cpu"), "clk", "rstn", 12, 32)
+                .slave("uart", 0x010, &uart())
+                .slave("gpio", 0x100, &gpio())
+        )
+);
+
+mod_test!(
+    wishbone_interconnect,
+    Module::new("wishbone_interconnect", "clk", "rstn")
+        .input("clk", 1)
+        .input("rstn", 1)
+        .add(
+            Interconnect::wishbone(Some("cpu"), "clk", "rstn", 12, 32)
+                .slave("uart", 0x010, &uart())
+                .slave("gpio", 0x100, &gpio())
+        )
+);
@@ -0,0 +1,29 @@
+use ruverta::{
+    bus::{AXILiteSlave, RegList},
+    mod_test,
+    module::Module,
+};
+
+mod_test!(
+    interrupt,
+    Module::new("interrupt", "clk", "rstn")
+        .input("clk", 1)
+        .input("rstn", 1)
+        .add(AXILiteSlave::new(
+            Some("cbus"),
+            "clk",
+            "rstn",
+            RegList::new()
+                .read_write("ctrl", 8, 1)
+                .read_only("status", 8, 1)
+                .field("rx_valid", 0, 1)
+                .interrupt()
+                .field("tx_empty", 1, 1)
+                .interrupt()
+                .field("count", 4, 4)
+                .read_only("error", 1, 1)
+                .field("error", 0, 1)
+                .interrupt()
+                .allocate_greedy(32, 8),
+        ))
+);
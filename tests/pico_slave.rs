@@ -1,11 +1,9 @@
-#[cfg(feature = "unstable")]
 use ruverta::{
     bus::{PicoSlave, RegList},
     mod_test,
     module::Module,
 };
 
-#[cfg(feature = "unstable")]
 mod_test!(
     pico_slave,
     Module::new("pico_slave", "clk", "rstn")
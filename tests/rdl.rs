@@ -0,0 +1,83 @@
+use ruverta::{
+    bus::{AXILiteSlave, RegList},
+    mod_test,
+    module::Module,
+};
+
+mod_test!(
+    rdl,
+    Module::new("rdl", "clk", "rstn")
+        .input("clk", 1)
+        .input("rstn", 1)
+        .add(AXILiteSlave::new(
+            Some("cbus"),
+            "clk",
+            "rstn",
+            RegList::from_rdl(include_str!("rdl/uart.rdl"))
+                .unwrap()
+                .allocate_greedy(32, 8),
+        ))
+);
+
+#[test]
+fn rdl_error() {
+    let err = RegList::from_rdl("addrmap top {\n  reg { field {} a; } r @ 0x0\n};").unwrap_err();
+    assert_eq!(err.line, 3);
+}
+
+#[test]
+fn rdl_field_overlap() {
+    let err = RegList::from_rdl(
+        "addrmap top {\n  reg {\n    field {} a[3:0];\n    field {} b[4:2];\n  } r;\n};",
+    )
+    .unwrap_err();
+    assert_eq!(err.line, 4);
+    assert_eq!(err.msg, "b: Field overlaps a");
+}
+
+#[test]
+fn rdl_regwidth() {
+    let err =
+        RegList::from_rdl("addrmap top {\n  default regwidth = 4;\n  reg { field {} a; } r;\n};")
+            .unwrap_err();
+    assert_eq!(err.line, 1);
+    assert_eq!(err.msg, "regwidth 4 is not a power of two of at least 8");
+
+    let err = RegList::from_rdl("addrmap top {\n  reg { regwidth = 24; field {} a; } r;\n};")
+        .unwrap_err();
+    assert_eq!(err.line, 2);
+}
+
+#[test]
+fn rdl_regwidth_wide() {
+    let err = RegList::from_rdl(
+        "addrmap top {\n  reg {\n    regwidth = 128;\n    field {} a[127:100] = 1;\n  } r;\n};",
+    )
+    .unwrap_err();
+    assert_eq!(err.line, 5);
+    assert_eq!(err.msg, "regwidth 128 is wider than 64 bits");
+}
+
+#[test]
+fn rdl_reset_width() {
+    let err = RegList::from_rdl(
+        "addrmap top {\n  reg {\n    field {} a[1:0] = 7;\n    field {} b[2:2];\n  } r;\n};",
+    )
+    .unwrap_err();
+    assert_eq!(err.line, 3);
+    assert_eq!(err.msg, "a: Reset value is wider than field");
+}
+
+#[test]
+fn rdl_alignment() {
+    let err = RegList::from_rdl(
+        "addrmap top {\n  reg { field {} a; } r0;\n  reg { field {} a; } r1 @ 0x6;\n};",
+    )
+    .unwrap_err();
+    assert_eq!(err.line, 3);
+    assert_eq!(err.msg, "r1: Register is not aligned to the 32-bit word");
+
+    let err = RegList::from_rdl("addrmap top {\n  reg { regwidth = 64; field {} a[40:0]; } r;\n};")
+        .unwrap_err();
+    assert_eq!(err.msg, "r: Register is wider than the word");
+}
@@ -0,0 +1,27 @@
+use ruverta::{
+    bus::{AXILiteSlave, RegList},
+    mod_test,
+    module::Module,
+};
+
+mod_test!(
+    reg_array,
+    Module::new("reg_array", "clk", "rstn")
+        .input("clk", 1)
+        .input("rstn", 1)
+        .add(AXILiteSlave::new(
+            Some("cbus"),
+            "clk",
+            "rstn",
+            RegList::new()
+                .read_write("ctrl", 8, 1)
+                .write_only("kick", 1, 1)
+                .read_write("lut", 16, 4)
+                .base(0x10)
+                .stride(2)
+                .read_write("desc", 32, 2)
+                .stride(4)
+                .read_only("status", 8, 1)
+                .allocate_greedy(32, 8),
+        ))
+);
@@ -0,0 +1,49 @@
+use ruverta::bus::{MemMap, RegList};
+use std::{fs, path::PathBuf};
+
+fn mem() -> MemMap {
+    RegList::new()
+        .read_write("ctrl", 8, 1)
+        .reset(0x81)
+        .field("enable", 0, 1)
+        .field("mode", 4, 3)
+        .read_only("status", 8, 1)
+        .field("rx_valid", 0, 1)
+        .interrupt()
+        .trigger("start")
+        .read_write("lut", 16, 4)
+        .base(0x10)
+        .stride(2)
+        .allocate_greedy(32, 8)
+}
+
+fn write(dir: &str, file: &str, code: Vec<String>) {
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push(dir);
+    fs::create_dir_all(&path).unwrap();
+    path.push(file);
+    fs::write(path, code.join("\n")).unwrap();
+}
+
+#[test]
+fn svd() {
+    write(
+        "tests/xml",
+        "reg_export.svd",
+        mem().svd("uart", 0x4000_0000),
+    );
+}
+
+#[test]
+fn ipxact() {
+    write(
+        "tests/xml",
+        "reg_export.xml",
+        mem().ipxact("example.com", "uart"),
+    );
+}
+
+#[test]
+fn markdown() {
+    write("tests/doc", "reg_export.md", mem().markdown("UART"));
+}
@@ -0,0 +1,218 @@
+//! Register access layer for `uart`
+
+use core::ptr::{read_volatile, write_volatile};
+
+/// `uart` register block
+#[derive(Debug, Clone, Copy)]
+pub struct Uart {
+    base: usize,
+}
+
+impl Uart {
+    /// # Safety
+    /// `base` must be the address of the `uart` register block.
+    pub const unsafe fn new(base: usize) -> Self {
+        Self { base }
+    }
+    pub fn ctrl(&self) -> Ctrl {
+        Ctrl { addr: self.base }
+    }
+    pub fn status(&self) -> Status {
+        Status { addr: self.base + 0x4 }
+    }
+    pub fn tx_data(&self) -> TxData {
+        TxData { addr: self.base + 0x8 }
+    }
+    pub fn start(&self) -> Start {
+        Start { addr: self.base + 0xc }
+    }
+    pub fn lut(&self, i: usize) -> Lut {
+        assert!(i < 4);
+        Lut { addr: self.base + 0x40 + i * 0x8 }
+    }
+    pub fn int_status(&self) -> IntStatus {
+        IntStatus { addr: self.base + 0x60 }
+    }
+    pub fn int_enable(&self) -> IntEnable {
+        IntEnable { addr: self.base + 0x64 }
+    }
+    pub fn int_test(&self) -> IntTest {
+        IntTest { addr: self.base + 0x68 }
+    }
+}
+
+/// `ctrl` : read-write
+#[derive(Debug, Clone, Copy)]
+pub struct Ctrl {
+    addr: usize,
+}
+
+impl Ctrl {
+    pub const MASK: u32 = 0xff;
+    pub const RESET: u32 = 0x81;
+    pub const ENABLE_SHIFT: u32 = 0;
+    pub const ENABLE_MASK: u32 = 0x1;
+    pub const MODE_SHIFT: u32 = 4;
+    pub const MODE_MASK: u32 = 0x70;
+    pub fn read(&self) -> u32 {
+        let val = unsafe { read_volatile(self.addr as *const u32) };
+        val & Self::MASK
+    }
+    pub fn write(&self, val: u32) {
+        unsafe { write_volatile(self.addr as *mut u32, val & Self::MASK) }
+    }
+    pub fn modify(&self, f: impl FnOnce(u32) -> u32) {
+        self.write(f(self.read()))
+    }
+    pub fn enable(&self) -> u32 {
+        (self.read() & Self::ENABLE_MASK) >> Self::ENABLE_SHIFT
+    }
+    pub fn set_enable(&self, val: u32) {
+        self.modify(|r| (r & !Self::ENABLE_MASK) | ((val << Self::ENABLE_SHIFT) & Self::ENABLE_MASK))
+    }
+    pub fn mode(&self) -> u32 {
+        (self.read() & Self::MODE_MASK) >> Self::MODE_SHIFT
+    }
+    pub fn set_mode(&self, val: u32) {
+        self.modify(|r| (r & !Self::MODE_MASK) | ((val << Self::MODE_SHIFT) & Self::MODE_MASK))
+    }
+}
+
+/// `status` : read-only
+#[derive(Debug, Clone, Copy)]
+pub struct Status {
+    addr: usize,
+}
+
+impl Status {
+    pub const MASK: u32 = 0xff;
+    pub const RX_VALID_SHIFT: u32 = 0;
+    pub const RX_VALID_MASK: u32 = 0x1;
+    pub fn read(&self) -> u32 {
+        let val = unsafe { read_volatile(self.addr as *const u32) };
+        val & Self::MASK
+    }
+    pub fn rx_valid(&self) -> u32 {
+        (self.read() & Self::RX_VALID_MASK) >> Self::RX_VALID_SHIFT
+    }
+}
+
+/// `tx_data` : write-only
+#[derive(Debug, Clone, Copy)]
+pub struct TxData {
+    addr: usize,
+}
+
+impl TxData {
+    pub const MASK: u32 = 0xff;
+    pub const RESET: u32 = 0x0;
+    pub fn write(&self, val: u32) {
+        unsafe { write_volatile(self.addr as *mut u32, val & Self::MASK) }
+    }
+}
+
+/// `start` : trigger
+#[derive(Debug, Clone, Copy)]
+pub struct Start {
+    addr: usize,
+}
+
+impl Start {
+    pub const MASK: u32 = 0x1;
+    pub const RESET: u32 = 0x0;
+    pub fn read(&self) -> u32 {
+        let val = unsafe { read_volatile(self.addr as *const u32) };
+        val & Self::MASK
+    }
+    pub fn write(&self, val: u32) {
+        unsafe { write_volatile(self.addr as *mut u32, val & Self::MASK) }
+    }
+}
+
+/// `lut` : read-write
+#[derive(Debug, Clone, Copy)]
+pub struct Lut {
+    addr: usize,
+}
+
+impl Lut {
+    pub const MASK: u32 = 0xffff;
+    pub const RESET: u32 = 0x0;
+    pub fn read(&self) -> u32 {
+        let val = unsafe { read_volatile(self.addr as *const u32) };
+        val & Self::MASK
+    }
+    pub fn write(&self, val: u32) {
+        unsafe { write_volatile(self.addr as *mut u32, val & Self::MASK) }
+    }
+    pub fn modify(&self, f: impl FnOnce(u32) -> u32) {
+        self.write(f(self.read()))
+    }
+}
+
+/// `int_status` : read, write 1 to clear
+#[derive(Debug, Clone, Copy)]
+pub struct IntStatus {
+    addr: usize,
+}
+
+impl IntStatus {
+    pub const MASK: u32 = 0x1;
+    pub const RESET: u32 = 0x0;
+    pub const RX_VALID_SHIFT: u32 = 0;
+    pub const RX_VALID_MASK: u32 = 0x1;
+    pub fn read(&self) -> u32 {
+        let val = unsafe { read_volatile(self.addr as *const u32) };
+        val & Self::MASK
+    }
+    pub fn write(&self, val: u32) {
+        unsafe { write_volatile(self.addr as *mut u32, val & Self::MASK) }
+    }
+    pub fn rx_valid(&self) -> u32 {
+        (self.read() & Self::RX_VALID_MASK) >> Self::RX_VALID_SHIFT
+    }
+}
+
+/// `int_enable` : read-write
+#[derive(Debug, Clone, Copy)]
+pub struct IntEnable {
+    addr: usize,
+}
+
+impl IntEnable {
+    pub const MASK: u32 = 0x1;
+    pub const RESET: u32 = 0x0;
+    pub const RX_VALID_SHIFT: u32 = 0;
+    pub const RX_VALID_MASK: u32 = 0x1;
+    pub fn read(&self) -> u32 {
+        let val = unsafe { read_volatile(self.addr as *const u32) };
+        val & Self::MASK
+    }
+    pub fn write(&self, val: u32) {
+        unsafe { write_volatile(self.addr as *mut u32, val & Self::MASK) }
+    }
+    pub fn modify(&self, f: impl FnOnce(u32) -> u32) {
+        self.write(f(self.read()))
+    }
+    pub fn rx_valid(&self) -> u32 {
+        (self.read() & Self::RX_VALID_MASK) >> Self::RX_VALID_SHIFT
+    }
+    pub fn set_rx_valid(&self, val: u32) {
+        self.modify(|r| (r & !Self::RX_VALID_MASK) | ((val << Self::RX_VALID_SHIFT) & Self::RX_VALID_MASK))
+    }
+}
+
+/// `int_test` : write 1 to set
+#[derive(Debug, Clone, Copy)]
+pub struct IntTest {
+    addr: usize,
+}
+
+impl IntTest {
+    pub const MASK: u32 = 0x1;
+    pub const RX_VALID_SHIFT: u32 = 0;
+    pub const RX_VALID_MASK: u32 = 0x1;
+    pub fn write(&self, val: u32) {
+        unsafe { write_volatile(self.addr as *mut u32, val & Self::MASK) }
+    }
+}
\ No newline at end of file
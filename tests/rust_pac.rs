@@ -0,0 +1,41 @@
+use ruverta::bus::RegList;
+use std::{fs, path::PathBuf};
+
+#[test]
+fn rust_pac() {
+    let mem = RegList::new()
+        .read_write("ctrl", 8, 1)
+        .reset(0x81)
+        .field("enable", 0, 1)
+        .field("mode", 4, 3)
+        .read_only("status", 8, 1)
+        .field("rx_valid", 0, 1)
+        .interrupt()
+        .write_only("tx_data", 8, 1)
+        .trigger("start")
+        .read_write("lut", 16, 4)
+        .base(0x10)
+        .stride(2)
+        .allocate_greedy(32, 8);
+    let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    path.push("tests/rust/rust_pac.rs");
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, mem.rust_pac("uart").join("\n")).unwrap();
+}
+
+#[allow(dead_code)]
+#[rustfmt::skip]
+#[path = "rust/rust_pac.rs"]
+mod uart;
+
+#[test]
+fn rust_pac_access() {
+    let mut regs = [0u32; 32];
+    let uart = unsafe { uart::Uart::new(regs.as_mut_ptr() as usize) };
+    uart.ctrl().write(0x1ff);
+    uart.ctrl().set_mode(5);
+    uart.lut(3).write(0x1234);
+    assert_eq!(uart.ctrl().mode(), 5);
+    assert_eq!(regs[0], 0xdf);
+    assert_eq!(regs[0x10 + 3 * 2], 0x1234);
+}
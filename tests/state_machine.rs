@@ -1,4 +1,8 @@
-use ruverta::{ext::StateMachine, mod_test, module::Module};
+use ruverta::{
+    ext::StateMachine,
+    mod_test,
+    module::{Module, Reset},
+};
 
 mod_test!(state_machine, {
     const INIT: &str = "INIT";
@@ -17,3 +21,20 @@ mod_test!(state_machine, {
         .input("in1", 1)
         .add(state_machine)
 });
+
+mod_test!(
+    state_machine_async_reset,
+    Module::new("state_machine_async_reset", "clk", "rstn")
+        .input("clk", 1)
+        .input("rst", 1)
+        .input("go", 1)
+        .add(
+            StateMachine::new("state")
+                .state("IDLE")
+                .jump("go", "BUSY")
+                .end()
+                .state("BUSY")
+                .r#else("IDLE")
+                .on("clk", Reset::high("rst").r#async())
+        )
+);
@@ -0,0 +1,43 @@
+use ruverta::{
+    ext::{ResetSync, SyncBit, SyncHandshake, SyncPulse},
+    mod_test,
+    module::Module,
+};
+
+mod_test!(
+    sync_bit,
+    Module::new("sync_bit", "clk", "rstn")
+        .input("clk", 1)
+        .input("rstn", 1)
+        .add(SyncBit::new("flag", "clk", "rstn").stages(3))
+);
+
+mod_test!(
+    sync_pulse,
+    Module::new("sync_pulse", "clk_a", "rstn_a")
+        .input("clk_a", 1)
+        .input("rstn_a", 1)
+        .input("clk_b", 1)
+        .input("rstn_b", 1)
+        .add(SyncPulse::new("kick", "clk_a", "rstn_a", "clk_b", "rstn_b"))
+);
+
+mod_test!(
+    sync_handshake,
+    Module::new("sync_handshake", "clk_a", "rstn_a")
+        .input("clk_a", 1)
+        .input("rstn_a", 1)
+        .input("clk_b", 1)
+        .input("rstn_b", 1)
+        .add(SyncHandshake::new(
+            "cfg", 16, "clk_a", "rstn_a", "clk_b", "rstn_b"
+        ))
+);
+
+mod_test!(
+    reset_sync,
+    Module::new("reset_sync", "clk", "arstn")
+        .input("clk", 1)
+        .input("arstn", 1)
+        .add(ResetSync::new("rstn", "clk", "arstn"))
+);
@@ -0,0 +1,135 @@
+module ahb_lite_slave
+(
+  input  logic        clk,
+  input  logic        rstn,
+  input  logic        hbus_hsel,
+  input  logic [ 7:0] hbus_haddr,
+  input  logic [ 1:0] hbus_htrans,
+  input  logic        hbus_hwrite,
+  input  logic [ 2:0] hbus_hsize,
+  input  logic [ 2:0] hbus_hburst,
+  input  logic [ 3:0] hbus_hprot,
+  input  logic [31:0] hbus_hwdata,
+  input  logic        hbus_hready,
+  output logic        hbus_hreadyout,
+  output logic        hbus_hresp,
+  output logic [31:0] hbus_hrdata
+)
+;
+  logic [31:0] csr_rw[ 3:0];
+  logic [ 7:0] csr_ro;
+  logic        csr_tw_trig;
+  logic        csr_tw_resp;
+  logic [ 5:0] hbus_aaddr;
+  logic        hbus_amiss;
+  always_comb
+    begin
+      hbus_aaddr = hbus_haddr[7:2];
+      case (hbus_aaddr)
+        0, 1, 2, 3, 4, 5: 
+        hbus_amiss = 0;
+        default: 
+        hbus_amiss = 1;
+      endcase
+    end
+  logic        hbus_dphase;
+  logic [ 5:0] hbus_daddr;
+  logic        hbus_dwrite;
+  logic [ 2:0] hbus_dsize;
+  logic [ 1:0] hbus_dlane;
+  logic        hbus_dmiss;
+  logic        hbus_derr;
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        begin
+          hbus_dphase <= 0;
+          hbus_daddr <= 0;
+          hbus_dwrite <= 0;
+          hbus_dsize <= 0;
+          hbus_dlane <= 0;
+          hbus_dmiss <= 0;
+        end
+      else
+        begin
+          if (hbus_hready)
+            begin
+              hbus_dphase <= hbus_hsel && hbus_htrans[1];
+              hbus_daddr <= hbus_aaddr;
+              hbus_dwrite <= hbus_hwrite;
+              hbus_dsize <= hbus_hsize;
+              hbus_dlane <= hbus_haddr[1:0];
+              hbus_dmiss <= hbus_amiss;
+            end
+        end
+    end
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        hbus_derr <= 0;
+      else
+        hbus_derr <= hbus_dphase && hbus_dmiss && !hbus_derr;
+    end
+  always_comb
+    begin
+      hbus_hreadyout = !(hbus_dphase && hbus_dmiss && !hbus_derr);
+      hbus_hresp = hbus_dphase && hbus_dmiss;
+    end
+  logic [ 3:0] hbus_strb;
+  always_comb
+    hbus_strb = ((1 << (1 << hbus_dsize)) - 1) << hbus_dlane;
+  logic [31:0] hbus_wmask;
+  always_comb
+    hbus_wmask = {{8{hbus_strb[3]}}, {8{hbus_strb[2]}}, {8{hbus_strb[1]}}, {8{hbus_strb[0]}}};
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        begin
+          csr_rw[0] <= 0;
+          csr_rw[1] <= 0;
+          csr_rw[2] <= 0;
+          csr_rw[3] <= 0;
+          csr_tw_trig <= 0;
+        end
+      else
+        begin
+          if (hbus_dphase && hbus_dwrite && !hbus_dmiss)
+            begin
+              case (hbus_daddr)
+                0: 
+                csr_rw[0] <= (csr_rw[0] & ~hbus_wmask[31:0]) | (hbus_hwdata[31:0] & hbus_wmask[31:0]);
+                1: 
+                csr_rw[1] <= (csr_rw[1] & ~hbus_wmask[31:0]) | (hbus_hwdata[31:0] & hbus_wmask[31:0]);
+                2: 
+                csr_rw[2] <= (csr_rw[2] & ~hbus_wmask[31:0]) | (hbus_hwdata[31:0] & hbus_wmask[31:0]);
+                3: 
+                csr_rw[3] <= (csr_rw[3] & ~hbus_wmask[31:0]) | (hbus_hwdata[31:0] & hbus_wmask[31:0]);
+                5: 
+                csr_tw_trig <= (csr_tw_trig & ~hbus_wmask[0:0]) | (hbus_hwdata[0:0] & hbus_wmask[0:0]);
+                default: 
+                ;
+              endcase
+            end
+        end
+    end
+  always_comb
+    begin
+      hbus_hrdata = 0;
+      case (hbus_daddr)
+        0: 
+        hbus_hrdata[31:0] = csr_rw[0];
+        1: 
+        hbus_hrdata[31:0] = csr_rw[1];
+        2: 
+        hbus_hrdata[31:0] = csr_rw[2];
+        3: 
+        hbus_hrdata[31:0] = csr_rw[3];
+        4: 
+        hbus_hrdata[7:0] = csr_ro;
+        5: 
+        hbus_hrdata[0:0] = csr_tw_resp;
+        default: 
+        hbus_hrdata = 0;
+      endcase
+    end
+endmodule
\ No newline at end of file
@@ -0,0 +1,88 @@
+module apb_interconnect
+(
+  input  logic        clk,
+  input  logic        rstn,
+  input  logic [11:0] cpu_paddr,
+  input  logic        cpu_psel,
+  input  logic        cpu_penable,
+  input  logic        cpu_pwrite,
+  input  logic [ 2:0] cpu_pprot,
+  input  logic [31:0] cpu_pwdata,
+  input  logic [ 3:0] cpu_pstrb,
+  output logic [31:0] cpu_prdata,
+  output logic        cpu_pready,
+  output logic        cpu_pslverr,
+  output logic [ 3:0] uart_paddr,
+  output logic        uart_psel,
+  output logic        uart_penable,
+  output logic        uart_pwrite,
+  output logic [ 2:0] uart_pprot,
+  output logic [31:0] uart_pwdata,
+  output logic [ 3:0] uart_pstrb,
+  input  logic [31:0] uart_prdata,
+  input  logic        uart_pready,
+  input  logic        uart_pslverr,
+  output logic [ 7:0] gpio_paddr,
+  output logic        gpio_psel,
+  output logic        gpio_penable,
+  output logic        gpio_pwrite,
+  output logic [ 2:0] gpio_pprot,
+  output logic [31:0] gpio_pwdata,
+  output logic [ 3:0] gpio_pstrb,
+  input  logic [31:0] gpio_prdata,
+  input  logic        gpio_pready,
+  input  logic        gpio_pslverr
+)
+;
+  logic [ 1:0] cpu_dec;
+  always_comb
+    begin
+      if (cpu_paddr[11:4] == 1)
+        cpu_dec = 0;
+      else if (cpu_paddr[11:8] == 1)
+        cpu_dec = 1;
+      else
+        cpu_dec = 2;
+    end
+  always_comb
+    begin
+      uart_paddr = cpu_paddr[3:0];
+      uart_psel = cpu_psel && cpu_dec == 0;
+      uart_penable = cpu_penable;
+      uart_pwrite = cpu_pwrite;
+      uart_pprot = cpu_pprot;
+      uart_pwdata = cpu_pwdata;
+      uart_pstrb = cpu_pstrb;
+    end
+  always_comb
+    begin
+      gpio_paddr = cpu_paddr[7:0];
+      gpio_psel = cpu_psel && cpu_dec == 1;
+      gpio_penable = cpu_penable;
+      gpio_pwrite = cpu_pwrite;
+      gpio_pprot = cpu_pprot;
+      gpio_pwdata = cpu_pwdata;
+      gpio_pstrb = cpu_pstrb;
+    end
+  always_comb
+    case (cpu_dec)
+      0: 
+      begin
+        cpu_prdata = uart_prdata;
+        cpu_pready = uart_pready;
+        cpu_pslverr = uart_pslverr;
+      end
+      1: 
+      begin
+        cpu_prdata = gpio_prdata;
+        cpu_pready = gpio_pready;
+        cpu_pslverr = gpio_pslverr;
+      end
+      default: 
+      begin
+        cpu_prdata = 0;
+        cpu_pready = 1;
+        cpu_pslverr = 1;
+      end
+    endcase
+endmodule
\ No newline at end of file
@@ -0,0 +1,78 @@
+module apb_master
+(
+  input  logic        clk,
+  input  logic        rstn,
+  output logic [15:0] pbus_paddr,
+  output logic        pbus_psel,
+  output logic        pbus_penable,
+  output logic        pbus_pwrite,
+  output logic [ 2:0] pbus_pprot,
+  output logic [31:0] pbus_pwdata,
+  output logic [ 3:0] pbus_pstrb,
+  input  logic [31:0] pbus_prdata,
+  input  logic        pbus_pready,
+  input  logic        pbus_pslverr
+)
+;
+  logic        pbus_start;
+  logic        pbus_write;
+  logic [15:0] pbus_addr;
+  logic [31:0] pbus_wr_data;
+  logic [ 3:0] pbus_wr_strb;
+  logic        pbus_busy;
+  logic        pbus_done;
+  logic [31:0] pbus_rd_data;
+  logic        pbus_error;
+  always_comb
+    pbus_pprot = 0;
+  always_comb
+    pbus_busy = pbus_psel;
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        begin
+          pbus_paddr <= 0;
+          pbus_psel <= 0;
+          pbus_penable <= 0;
+          pbus_pwrite <= 0;
+          pbus_pwdata <= 0;
+          pbus_pstrb <= 0;
+        end
+      else
+        begin
+          if (pbus_start && !pbus_busy)
+            begin
+              pbus_paddr <= pbus_addr;
+              pbus_psel <= 1;
+              pbus_pwrite <= pbus_write;
+              pbus_pwdata <= pbus_wr_data;
+              pbus_pstrb <= pbus_write ? pbus_wr_strb : 0;
+            end
+          else if (pbus_psel && !pbus_penable)
+            pbus_penable <= 1;
+          else if (pbus_psel && pbus_penable && pbus_pready)
+            begin
+              pbus_psel <= 0;
+              pbus_penable <= 0;
+            end
+        end
+    end
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        begin
+          pbus_done <= 0;
+          pbus_rd_data <= 0;
+          pbus_error <= 0;
+        end
+      else
+        begin
+          pbus_done <= pbus_psel && pbus_penable && pbus_pready;
+          if (pbus_psel && pbus_penable && pbus_pready)
+            begin
+              pbus_rd_data <= pbus_prdata;
+              pbus_error <= pbus_pslverr;
+            end
+        end
+    end
+endmodule
\ No newline at end of file
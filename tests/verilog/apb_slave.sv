@@ -0,0 +1,96 @@
+module apb_slave
+(
+  input  logic        clk,
+  input  logic        rstn,
+  input  logic [ 7:0] pbus_paddr,
+  input  logic        pbus_psel,
+  input  logic        pbus_penable,
+  input  logic        pbus_pwrite,
+  input  logic [ 2:0] pbus_pprot,
+  input  logic [31:0] pbus_pwdata,
+  input  logic [ 3:0] pbus_pstrb,
+  output logic [31:0] pbus_prdata,
+  output logic        pbus_pready,
+  output logic        pbus_pslverr
+)
+;
+  logic [15:0] csr_rw[ 3:0];
+  logic [ 7:0] csr_ro;
+  logic        csr_tw_trig;
+  logic        csr_tw_resp;
+  logic [ 1:0] pbus_wait;
+  always_comb
+    pbus_pready = pbus_wait == 2;
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        pbus_wait <= 0;
+      else
+        begin
+          if (pbus_psel && pbus_penable && !pbus_pready)
+            pbus_wait <= pbus_wait + 1;
+          else
+            pbus_wait <= 0;
+        end
+    end
+  logic [31:0] pbus_wmask;
+  always_comb
+    pbus_wmask = {{8{pbus_pstrb[3]}}, {8{pbus_pstrb[2]}}, {8{pbus_pstrb[1]}}, {8{pbus_pstrb[0]}}};
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        begin
+          csr_rw[0] <= 0;
+          csr_rw[1] <= 0;
+          csr_rw[2] <= 0;
+          csr_rw[3] <= 0;
+          csr_tw_trig <= 0;
+        end
+      else
+        begin
+          if (pbus_psel && pbus_penable && pbus_pwrite && pbus_pready)
+            begin
+              case (pbus_paddr[7:2])
+                0: 
+                csr_rw[0] <= (csr_rw[0] & ~pbus_wmask[15:0]) | (pbus_pwdata[15:0] & pbus_wmask[15:0]);
+                1: 
+                csr_rw[1] <= (csr_rw[1] & ~pbus_wmask[15:0]) | (pbus_pwdata[15:0] & pbus_wmask[15:0]);
+                2: 
+                csr_rw[2] <= (csr_rw[2] & ~pbus_wmask[15:0]) | (pbus_pwdata[15:0] & pbus_wmask[15:0]);
+                3: 
+                csr_rw[3] <= (csr_rw[3] & ~pbus_wmask[15:0]) | (pbus_pwdata[15:0] & pbus_wmask[15:0]);
+                5: 
+                csr_tw_trig <= (csr_tw_trig & ~pbus_wmask[0:0]) | (pbus_pwdata[0:0] & pbus_wmask[0:0]);
+                default: 
+                ;
+              endcase
+            end
+        end
+    end
+  always_comb
+    begin
+      pbus_prdata = 0;
+      case (pbus_paddr[7:2])
+        0: 
+        pbus_prdata[15:0] = csr_rw[0];
+        1: 
+        pbus_prdata[15:0] = csr_rw[1];
+        2: 
+        pbus_prdata[15:0] = csr_rw[2];
+        3: 
+        pbus_prdata[15:0] = csr_rw[3];
+        4: 
+        pbus_prdata[7:0] = csr_ro;
+        5: 
+        pbus_prdata[0:0] = csr_tw_resp;
+        default: 
+        pbus_prdata = 0;
+      endcase
+      case (pbus_paddr[7:2])
+        0, 1, 2, 3, 4, 5: 
+        pbus_pslverr = 0;
+        default: 
+        pbus_pslverr = 1;
+      endcase
+    end
+endmodule
\ No newline at end of file
@@ -0,0 +1,58 @@
+module apb_slave_async_reset
+(
+  input  logic        pclk,
+  input  logic        presetn,
+  input  logic [ 7:0] paddr,
+  input  logic        psel,
+  input  logic        penable,
+  input  logic        pwrite,
+  input  logic [ 2:0] pprot,
+  input  logic [31:0] pwdata,
+  input  logic [ 3:0] pstrb,
+  output logic [31:0] prdata,
+  output logic        pready,
+  output logic        pslverr
+)
+;
+  logic [15:0] csr_rw;
+  always_comb
+    pready = 1;
+  logic [31:0] wmask;
+  always_comb
+    wmask = {{8{pstrb[3]}}, {8{pstrb[2]}}, {8{pstrb[1]}}, {8{pstrb[0]}}};
+  always_ff @(posedge pclk or negedge presetn)
+    begin
+      if (!presetn)
+        begin
+          csr_rw <= 0;
+        end
+      else
+        begin
+          if (psel && penable && pwrite && pready)
+            begin
+              case (paddr[7:2])
+                0: 
+                csr_rw <= (csr_rw & ~wmask[15:0]) | (pwdata[15:0] & wmask[15:0]);
+                default: 
+                ;
+              endcase
+            end
+        end
+    end
+  always_comb
+    begin
+      prdata = 0;
+      case (paddr[7:2])
+        0: 
+        prdata[15:0] = csr_rw;
+        default: 
+        prdata = 0;
+      endcase
+      case (paddr[7:2])
+        0: 
+        pslverr = 0;
+        default: 
+        pslverr = 1;
+      endcase
+    end
+endmodule
\ No newline at end of file
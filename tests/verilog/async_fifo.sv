@@ -0,0 +1,114 @@
+module async_fifo
+(
+  input  logic        wclk,
+  input  logic        wrstn,
+  input  logic        rclk,
+  input  logic        rrstn
+)
+;
+  logic        cross_push;
+  logic [15:0] cross_wdata;
+  logic        cross_full;
+  logic        cross_overflow;
+  logic        cross_pop;
+  logic [15:0] cross_rdata;
+  logic        cross_empty;
+  logic        cross_underflow;
+  (* CDC_MEMORY = "TRUE" *) logic [15:0] cross_buf[15:0];
+  logic        cross_we;
+  logic        cross_re;
+  logic [ 4:0] cross_wbin;
+  logic [ 4:0] cross_wgray;
+  logic [ 4:0] cross_wnext;
+  logic [ 4:0] cross_rbin;
+  logic [ 4:0] cross_rgray;
+  logic [ 4:0] cross_rnext;
+  (* ASYNC_REG = "TRUE" *) logic [ 4:0] cross_wq1_rgray;
+  (* ASYNC_REG = "TRUE" *) logic [ 4:0] cross_wq2_rgray;
+  always_ff @(posedge wclk)
+    begin
+      if (!wrstn)
+        begin
+          cross_wq1_rgray <= 0;
+          cross_wq2_rgray <= 0;
+        end
+      else
+        begin
+          cross_wq1_rgray <= cross_rgray;
+          cross_wq2_rgray <= cross_wq1_rgray;
+        end
+    end
+  (* ASYNC_REG = "TRUE" *) logic [ 4:0] cross_rq1_wgray;
+  (* ASYNC_REG = "TRUE" *) logic [ 4:0] cross_rq2_wgray;
+  always_ff @(posedge rclk)
+    begin
+      if (!rrstn)
+        begin
+          cross_rq1_wgray <= 0;
+          cross_rq2_wgray <= 0;
+        end
+      else
+        begin
+          cross_rq1_wgray <= cross_wgray;
+          cross_rq2_wgray <= cross_rq1_wgray;
+        end
+    end
+  always_comb
+    begin
+      cross_full = cross_wgray == {~cross_wq2_rgray[4:3], cross_wq2_rgray[2:0]};
+      cross_empty = cross_rgray == cross_rq2_wgray;
+      cross_we = cross_push && !cross_full;
+      cross_re = cross_pop && !cross_empty;
+      cross_wnext = cross_wbin + 1;
+      cross_rnext = cross_rbin + 1;
+    end
+  always_ff @(posedge wclk)
+    begin
+      if (!wrstn)
+        begin
+          cross_wbin <= 0;
+          cross_wgray <= 0;
+          cross_overflow <= 0;
+        end
+      else
+        begin
+          if (cross_we)
+            begin
+              cross_wbin <= cross_wnext;
+              cross_wgray <= (cross_wnext >> 1) ^ cross_wnext;
+            end
+          cross_overflow <= cross_push && cross_full;
+        end
+    end
+  always_ff @(posedge rclk)
+    begin
+      if (!rrstn)
+        begin
+          cross_rbin <= 0;
+          cross_rgray <= 0;
+          cross_underflow <= 0;
+        end
+      else
+        begin
+          if (cross_re)
+            begin
+              cross_rbin <= cross_rnext;
+              cross_rgray <= (cross_rnext >> 1) ^ cross_rnext;
+            end
+          cross_underflow <= cross_pop && cross_empty;
+        end
+    end
+  always_ff @(posedge wclk)
+    if (cross_we)
+      cross_buf[cross_wbin[3:0]] <= cross_wdata;
+  always_ff @(posedge rclk)
+    begin
+      if (!rrstn)
+        cross_rdata <= 0;
+      else
+        begin
+          if (cross_re)
+            cross_rdata <= cross_buf[cross_rbin[3:0]];
+        end
+    end
+endmodule
\ No newline at end of file
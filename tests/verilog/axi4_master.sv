@@ -0,0 +1,164 @@
+module axi4_master
+(
+  input  logic        clk,
+  input  logic        rstn,
+  output logic [ 3:0] mbus_awid,
+  output logic [31:0] mbus_awaddr,
+  output logic [ 7:0] mbus_awlen,
+  output logic [ 2:0] mbus_awsize,
+  output logic [ 1:0] mbus_awburst,
+  output logic        mbus_awvalid,
+  input  logic        mbus_awready,
+  output logic [63:0] mbus_wdata,
+  output logic [ 7:0] mbus_wstrb,
+  output logic        mbus_wlast,
+  output logic        mbus_wvalid,
+  input  logic        mbus_wready,
+  input  logic [ 3:0] mbus_bid,
+  input  logic [ 1:0] mbus_bresp,
+  input  logic        mbus_bvalid,
+  output logic        mbus_bready,
+  output logic [ 3:0] mbus_arid,
+  output logic [31:0] mbus_araddr,
+  output logic [ 7:0] mbus_arlen,
+  output logic [ 2:0] mbus_arsize,
+  output logic [ 1:0] mbus_arburst,
+  output logic        mbus_arvalid,
+  input  logic        mbus_arready,
+  input  logic [ 3:0] mbus_rid,
+  input  logic [63:0] mbus_rdata,
+  input  logic [ 1:0] mbus_rresp,
+  input  logic        mbus_rlast,
+  input  logic        mbus_rvalid,
+  output logic        mbus_rready
+)
+;
+  logic        mbus_start;
+  logic        mbus_write;
+  logic [31:0] mbus_addr;
+  logic [ 7:0] mbus_len;
+  logic        mbus_busy;
+  logic [63:0] mbus_wr_data;
+  logic [ 7:0] mbus_wr_strb;
+  logic        mbus_wr_valid;
+  logic        mbus_wr_ready;
+  logic [63:0] mbus_rd_data;
+  logic        mbus_rd_valid;
+  logic        mbus_done;
+  logic        mbus_error;
+  always_comb
+    begin
+      mbus_awid = 0;
+      mbus_awsize = 3;
+      mbus_awburst = 2'b01;
+      mbus_arid = 0;
+      mbus_arsize = 3;
+      mbus_arburst = 2'b01;
+    end
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        begin
+          mbus_awaddr <= 0;
+          mbus_awlen <= 0;
+          mbus_awvalid <= 0;
+          mbus_araddr <= 0;
+          mbus_arlen <= 0;
+          mbus_arvalid <= 0;
+        end
+      else
+        begin
+          if (mbus_start && !mbus_busy)
+            begin
+              if (mbus_write)
+                begin
+                  mbus_awaddr <= mbus_addr;
+                  mbus_awlen <= mbus_len;
+                  mbus_awvalid <= 1;
+                end
+              else
+                begin
+                  mbus_araddr <= mbus_addr;
+                  mbus_arlen <= mbus_len;
+                  mbus_arvalid <= 1;
+                end
+            end
+          if (mbus_awvalid && mbus_awready)
+            mbus_awvalid <= 0;
+          if (mbus_arvalid && mbus_arready)
+            mbus_arvalid <= 0;
+        end
+    end
+  logic        mbus_wactive;
+  logic [ 7:0] mbus_wcnt;
+  always_comb
+    begin
+      mbus_wdata = mbus_wr_data;
+      mbus_wstrb = mbus_wr_strb;
+      mbus_wlast = mbus_wcnt == 0;
+      mbus_wvalid = mbus_wactive && mbus_wr_valid;
+      mbus_wr_ready = mbus_wactive && mbus_wready;
+    end
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        begin
+          mbus_wactive <= 0;
+          mbus_wcnt <= 0;
+        end
+      else
+        begin
+          if (mbus_start && !mbus_busy && mbus_write)
+            begin
+              mbus_wactive <= 1;
+              mbus_wcnt <= mbus_len;
+            end
+          else if (mbus_wvalid && mbus_wready)
+            begin
+              mbus_wcnt <= mbus_wcnt - 1;
+              if (mbus_wlast)
+                mbus_wactive <= 0;
+            end
+        end
+    end
+  always_comb
+    begin
+      mbus_bready = mbus_busy;
+      mbus_rready = mbus_busy;
+      mbus_rd_data = mbus_rdata;
+      mbus_rd_valid = mbus_rvalid && mbus_rready;
+    end
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        begin
+          mbus_busy <= 0;
+          mbus_done <= 0;
+          mbus_error <= 0;
+        end
+      else
+        begin
+          mbus_done <= 0;
+          if (mbus_start && !mbus_busy)
+            begin
+              mbus_busy <= 1;
+              mbus_error <= 0;
+            end
+          if (mbus_bvalid && mbus_bready)
+            begin
+              mbus_busy <= 0;
+              mbus_done <= 1;
+              mbus_error <= mbus_bresp[1];
+            end
+          if (mbus_rvalid && mbus_rready)
+            begin
+              mbus_error <= mbus_error || mbus_rresp[1];
+              if (mbus_rlast)
+                begin
+                  mbus_busy <= 0;
+                  mbus_done <= 1;
+                end
+            end
+        end
+    end
+endmodule
\ No newline at end of file
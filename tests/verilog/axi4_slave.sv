@@ -0,0 +1,368 @@
+module axi4_slave
+(
+  input  logic        clk,
+  input  logic        rstn,
+  input  logic [ 1:0] sbus_awid,
+  input  logic [ 7:0] sbus_awaddr,
+  input  logic [ 7:0] sbus_awlen,
+  input  logic [ 2:0] sbus_awsize,
+  input  logic [ 1:0] sbus_awburst,
+  input  logic        sbus_awvalid,
+  output logic        sbus_awready,
+  input  logic [31:0] sbus_wdata,
+  input  logic [ 3:0] sbus_wstrb,
+  input  logic        sbus_wlast,
+  input  logic        sbus_wvalid,
+  output logic        sbus_wready,
+  output logic [ 1:0] sbus_bid,
+  output logic [ 1:0] sbus_bresp,
+  output logic        sbus_bvalid,
+  input  logic        sbus_bready,
+  input  logic [ 1:0] sbus_arid,
+  input  logic [ 7:0] sbus_araddr,
+  input  logic [ 7:0] sbus_arlen,
+  input  logic [ 2:0] sbus_arsize,
+  input  logic [ 1:0] sbus_arburst,
+  input  logic        sbus_arvalid,
+  output logic        sbus_arready,
+  output logic [ 1:0] sbus_rid,
+  output logic [31:0] sbus_rdata,
+  output logic [ 1:0] sbus_rresp,
+  output logic        sbus_rlast,
+  output logic        sbus_rvalid,
+  input  logic        sbus_rready
+)
+;
+  logic [31:0] ctrl;
+  logic [31:0] lut[ 3:0];
+  logic [ 7:0] status;
+  logic [ 1:0] sbus_awid_q;
+  logic [ 7:0] sbus_awaddr_q;
+  logic [ 7:0] sbus_awlen_q;
+  logic [ 2:0] sbus_awsize_q;
+  logic [ 1:0] sbus_awburst_q;
+  logic [ 7:0] sbus_awincr;
+  logic [ 7:0] sbus_awwrap;
+  logic [ 7:0] sbus_awnext;
+  always_comb
+    begin
+      sbus_awincr = (sbus_awaddr_q & ~((1 << sbus_awsize_q) - 1)) + (1 << sbus_awsize_q);
+      sbus_awwrap = ((sbus_awlen_q + 1) << sbus_awsize_q) - 1;
+      case (sbus_awburst_q)
+        2'b00: 
+        sbus_awnext = sbus_awaddr_q;
+        2'b10: 
+        sbus_awnext = (sbus_awaddr_q & ~sbus_awwrap) | (sbus_awincr & sbus_awwrap);
+        default: 
+        sbus_awnext = sbus_awincr;
+      endcase
+    end
+  logic [ 1:0] sbus_arid_q;
+  logic [ 7:0] sbus_araddr_q;
+  logic [ 7:0] sbus_arlen_q;
+  logic [ 2:0] sbus_arsize_q;
+  logic [ 1:0] sbus_arburst_q;
+  logic [ 7:0] sbus_arincr;
+  logic [ 7:0] sbus_arwrap;
+  logic [ 7:0] sbus_arnext;
+  always_comb
+    begin
+      sbus_arincr = (sbus_araddr_q & ~((1 << sbus_arsize_q) - 1)) + (1 << sbus_arsize_q);
+      sbus_arwrap = ((sbus_arlen_q + 1) << sbus_arsize_q) - 1;
+      case (sbus_arburst_q)
+        2'b00: 
+        sbus_arnext = sbus_araddr_q;
+        2'b10: 
+        sbus_arnext = (sbus_araddr_q & ~sbus_arwrap) | (sbus_arincr & sbus_arwrap);
+        default: 
+        sbus_arnext = sbus_arincr;
+      endcase
+    end
+  logic        sbus_wmiss;
+  logic        sbus_rmiss;
+  logic [31:0] sbus_rd;
+  logic [31:0] sbus_wmask;
+  always_comb
+    sbus_wmask = {{8{sbus_wstrb[3]}}, {8{sbus_wstrb[2]}}, {8{sbus_wstrb[1]}}, {8{sbus_wstrb[0]}}};
+  logic [ 5:0] sbus_wword;
+  logic [ 5:0] sbus_rword;
+  always_comb
+    sbus_wword = sbus_awaddr_q[7:2];
+  always_comb
+    sbus_rword = sbus_araddr_q[7:2];
+  always_comb
+    case (sbus_wword)
+      0, 4, 5, 6, 7, 8: 
+      sbus_wmiss = 0;
+      default: 
+      sbus_wmiss = 1;
+    endcase
+  always_comb
+    case (sbus_rword)
+      0, 4, 5, 6, 7, 8: 
+      sbus_rmiss = 0;
+      default: 
+      sbus_rmiss = 1;
+    endcase
+  always_comb
+    begin
+      sbus_rd = 0;
+      case (sbus_rword)
+        0: 
+        sbus_rd[31:0] = ctrl;
+        4: 
+        sbus_rd[31:0] = lut[0];
+        5: 
+        sbus_rd[31:0] = lut[1];
+        6: 
+        sbus_rd[31:0] = lut[2];
+        7: 
+        sbus_rd[31:0] = lut[3];
+        8: 
+        sbus_rd[7:0] = status;
+        default: 
+        sbus_rd = 0;
+      endcase
+    end
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        begin
+          ctrl <= 0;
+          lut[0] <= 0;
+          lut[1] <= 0;
+          lut[2] <= 0;
+          lut[3] <= 0;
+        end
+      else
+        begin
+          if (sbus_wvalid && sbus_wready && !sbus_wmiss)
+            begin
+              case (sbus_wword)
+                0: 
+                ctrl <= (ctrl & ~sbus_wmask[31:0]) | (sbus_wdata[31:0] & sbus_wmask[31:0]);
+                4: 
+                lut[0] <= (lut[0] & ~sbus_wmask[31:0]) | (sbus_wdata[31:0] & sbus_wmask[31:0]);
+                5: 
+                lut[1] <= (lut[1] & ~sbus_wmask[31:0]) | (sbus_wdata[31:0] & sbus_wmask[31:0]);
+                6: 
+                lut[2] <= (lut[2] & ~sbus_wmask[31:0]) | (sbus_wdata[31:0] & sbus_wmask[31:0]);
+                7: 
+                lut[3] <= (lut[3] & ~sbus_wmask[31:0]) | (sbus_wdata[31:0] & sbus_wmask[31:0]);
+                default: 
+                ;
+              endcase
+            end
+        end
+    end
+  logic        sbus_wactive;
+  logic        sbus_werr;
+  logic        sbus_awq_push;
+  logic [22:0] sbus_awq_wdata;
+  logic        sbus_awq_pop;
+  logic [22:0] sbus_awq_rdata;
+  logic        sbus_awq_full;
+  logic        sbus_awq_empty;
+  logic [ 2:0] sbus_awq_count;
+  logic        sbus_awq_overflow;
+  logic        sbus_awq_underflow;
+  logic [22:0] sbus_awq_buf[ 3:0];
+  logic [ 1:0] sbus_awq_rptr;
+  logic [ 1:0] sbus_awq_wptr;
+  logic        sbus_awq_we;
+  logic        sbus_awq_re;
+  always_comb
+    begin
+      sbus_awq_full = sbus_awq_count == 4;
+      sbus_awq_empty = sbus_awq_count == 0;
+      sbus_awq_we = sbus_awq_push && !sbus_awq_full;
+      sbus_awq_re = sbus_awq_pop && !sbus_awq_empty;
+    end
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        begin
+          sbus_awq_wptr <= 0;
+          sbus_awq_rptr <= 0;
+          sbus_awq_count <= 0;
+          sbus_awq_overflow <= 0;
+          sbus_awq_underflow <= 0;
+        end
+      else
+        begin
+          if (sbus_awq_we)
+            sbus_awq_wptr <= sbus_awq_wptr + 1;
+          if (sbus_awq_re)
+            sbus_awq_rptr <= sbus_awq_rptr + 1;
+          if (sbus_awq_we && !sbus_awq_re)
+            sbus_awq_count <= sbus_awq_count + 1;
+          else if (!sbus_awq_we && sbus_awq_re)
+            sbus_awq_count <= sbus_awq_count - 1;
+          sbus_awq_overflow <= sbus_awq_push && sbus_awq_full;
+          sbus_awq_underflow <= sbus_awq_pop && sbus_awq_empty;
+        end
+    end
+  always_ff @(posedge clk)
+    if (sbus_awq_we)
+      sbus_awq_buf[sbus_awq_wptr] <= sbus_awq_wdata;
+  always_comb
+    sbus_awq_rdata = sbus_awq_buf[sbus_awq_rptr];
+  always_comb
+    begin
+      sbus_awready = !sbus_awq_full;
+      sbus_awq_push = sbus_awvalid && sbus_awready;
+      sbus_awq_wdata = {sbus_awid, sbus_awaddr, sbus_awlen, sbus_awsize, sbus_awburst};
+      sbus_awq_pop = !sbus_wactive && !sbus_awq_empty;
+    end
+  always_comb
+    sbus_wready = sbus_wactive && !sbus_bvalid;
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        begin
+          sbus_wactive <= 0;
+          sbus_werr <= 0;
+          sbus_awid_q <= 0;
+          sbus_awaddr_q <= 0;
+          sbus_awlen_q <= 0;
+          sbus_awsize_q <= 0;
+          sbus_awburst_q <= 0;
+          sbus_bid <= 0;
+          sbus_bresp <= 0;
+          sbus_bvalid <= 0;
+        end
+      else
+        begin
+          if (sbus_awq_pop)
+            begin
+              sbus_wactive <= 1;
+              sbus_werr <= 0;
+              sbus_awid_q <= sbus_awq_rdata[22:21];
+              sbus_awaddr_q <= sbus_awq_rdata[20:13];
+              sbus_awlen_q <= sbus_awq_rdata[12:5];
+              sbus_awsize_q <= sbus_awq_rdata[4:2];
+              sbus_awburst_q <= sbus_awq_rdata[1:0];
+            end
+          if (sbus_wvalid && sbus_wready)
+            begin
+              sbus_awaddr_q <= sbus_awnext;
+              sbus_werr <= sbus_werr || sbus_wmiss;
+              if (sbus_wlast)
+                begin
+                  sbus_bid <= sbus_awid_q;
+                  sbus_bresp <= sbus_werr || sbus_wmiss ? 2'b10 : 2'b00;
+                  sbus_bvalid <= 1;
+                end
+            end
+          if (sbus_bvalid && sbus_bready)
+            begin
+              sbus_bvalid <= 0;
+              sbus_wactive <= 0;
+            end
+        end
+    end
+  logic        sbus_ractive;
+  logic [ 7:0] sbus_rcnt;
+  logic        sbus_arq_push;
+  logic [22:0] sbus_arq_wdata;
+  logic        sbus_arq_pop;
+  logic [22:0] sbus_arq_rdata;
+  logic        sbus_arq_full;
+  logic        sbus_arq_empty;
+  logic [ 2:0] sbus_arq_count;
+  logic        sbus_arq_overflow;
+  logic        sbus_arq_underflow;
+  logic [22:0] sbus_arq_buf[ 3:0];
+  logic [ 1:0] sbus_arq_rptr;
+  logic [ 1:0] sbus_arq_wptr;
+  logic        sbus_arq_we;
+  logic        sbus_arq_re;
+  always_comb
+    begin
+      sbus_arq_full = sbus_arq_count == 4;
+      sbus_arq_empty = sbus_arq_count == 0;
+      sbus_arq_we = sbus_arq_push && !sbus_arq_full;
+      sbus_arq_re = sbus_arq_pop && !sbus_arq_empty;
+    end
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        begin
+          sbus_arq_wptr <= 0;
+          sbus_arq_rptr <= 0;
+          sbus_arq_count <= 0;
+          sbus_arq_overflow <= 0;
+          sbus_arq_underflow <= 0;
+        end
+      else
+        begin
+          if (sbus_arq_we)
+            sbus_arq_wptr <= sbus_arq_wptr + 1;
+          if (sbus_arq_re)
+            sbus_arq_rptr <= sbus_arq_rptr + 1;
+          if (sbus_arq_we && !sbus_arq_re)
+            sbus_arq_count <= sbus_arq_count + 1;
+          else if (!sbus_arq_we && sbus_arq_re)
+            sbus_arq_count <= sbus_arq_count - 1;
+          sbus_arq_overflow <= sbus_arq_push && sbus_arq_full;
+          sbus_arq_underflow <= sbus_arq_pop && sbus_arq_empty;
+        end
+    end
+  always_ff @(posedge clk)
+    if (sbus_arq_we)
+      sbus_arq_buf[sbus_arq_wptr] <= sbus_arq_wdata;
+  always_comb
+    sbus_arq_rdata = sbus_arq_buf[sbus_arq_rptr];
+  always_comb
+    begin
+      sbus_arready = !sbus_arq_full;
+      sbus_arq_push = sbus_arvalid && sbus_arready;
+      sbus_arq_wdata = {sbus_arid, sbus_araddr, sbus_arlen, sbus_arsize, sbus_arburst};
+      sbus_arq_pop = !sbus_ractive && !sbus_arq_empty;
+    end
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        begin
+          sbus_ractive <= 0;
+          sbus_rcnt <= 0;
+          sbus_arid_q <= 0;
+          sbus_araddr_q <= 0;
+          sbus_arlen_q <= 0;
+          sbus_arsize_q <= 0;
+          sbus_arburst_q <= 0;
+          sbus_rid <= 0;
+          sbus_rdata <= 0;
+          sbus_rresp <= 0;
+          sbus_rlast <= 0;
+          sbus_rvalid <= 0;
+        end
+      else
+        begin
+          if (sbus_rvalid && sbus_rready)
+            sbus_rvalid <= 0;
+          if (sbus_arq_pop)
+            begin
+              sbus_ractive <= 1;
+              sbus_rcnt <= sbus_arq_rdata[12:5];
+              sbus_arid_q <= sbus_arq_rdata[22:21];
+              sbus_araddr_q <= sbus_arq_rdata[20:13];
+              sbus_arlen_q <= sbus_arq_rdata[12:5];
+              sbus_arsize_q <= sbus_arq_rdata[4:2];
+              sbus_arburst_q <= sbus_arq_rdata[1:0];
+            end
+          else if (sbus_ractive && (!sbus_rvalid || sbus_rready))
+            begin
+              sbus_rid <= sbus_arid_q;
+              sbus_rdata <= sbus_rd;
+              sbus_rresp <= sbus_rmiss ? 2'b10 : 2'b00;
+              sbus_rlast <= sbus_rcnt == 0;
+              sbus_rvalid <= 1;
+              sbus_araddr_q <= sbus_arnext;
+              sbus_rcnt <= sbus_rcnt - 1;
+              if (sbus_rcnt == 0)
+                sbus_ractive <= 0;
+            end
+        end
+    end
+endmodule
\ No newline at end of file
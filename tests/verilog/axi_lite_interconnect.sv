@@ -0,0 +1,236 @@
+module axi_lite_interconnect
+(
+  input  logic        clk,
+  input  logic        rstn,
+  input  logic [11:0] cpu_awaddr,
+  input  logic        cpu_awvalid,
+  output logic        cpu_awready,
+  input  logic [31:0] cpu_wdata,
+  input  logic [ 3:0] cpu_wstrb,
+  input  logic        cpu_wvalid,
+  output logic        cpu_wready,
+  output logic [ 1:0] cpu_bresp,
+  output logic        cpu_bvalid,
+  input  logic        cpu_bready,
+  input  logic [11:0] cpu_araddr,
+  input  logic        cpu_arvalid,
+  output logic        cpu_arready,
+  output logic [31:0] cpu_rdata,
+  output logic [ 1:0] cpu_rresp,
+  output logic        cpu_rvalid,
+  input  logic        cpu_rready,
+  output logic [ 3:0] uart_awaddr,
+  output logic        uart_awvalid,
+  input  logic        uart_awready,
+  output logic [31:0] uart_wdata,
+  output logic [ 3:0] uart_wstrb,
+  output logic        uart_wvalid,
+  input  logic        uart_wready,
+  input  logic [ 1:0] uart_bresp,
+  input  logic        uart_bvalid,
+  output logic        uart_bready,
+  output logic [ 3:0] uart_araddr,
+  output logic        uart_arvalid,
+  input  logic        uart_arready,
+  input  logic [31:0] uart_rdata,
+  input  logic [ 1:0] uart_rresp,
+  input  logic        uart_rvalid,
+  output logic        uart_rready,
+  output logic [ 7:0] gpio_awaddr,
+  output logic        gpio_awvalid,
+  input  logic        gpio_awready,
+  output logic [31:0] gpio_wdata,
+  output logic [ 3:0] gpio_wstrb,
+  output logic        gpio_wvalid,
+  input  logic        gpio_wready,
+  input  logic [ 1:0] gpio_bresp,
+  input  logic        gpio_bvalid,
+  output logic        gpio_bready,
+  output logic [ 7:0] gpio_araddr,
+  output logic        gpio_arvalid,
+  input  logic        gpio_arready,
+  input  logic [31:0] gpio_rdata,
+  input  logic [ 1:0] gpio_rresp,
+  input  logic        gpio_rvalid,
+  output logic        gpio_rready
+)
+;
+  logic [ 1:0] cpu_wdec;
+  logic        cpu_wbusy;
+  logic [ 1:0] cpu_wsel;
+  logic [11:0] cpu_waddr;
+  logic [31:0] cpu_wdata_q;
+  logic [ 3:0] cpu_wstrb_q;
+  logic        cpu_awpend;
+  logic        cpu_wpend;
+  logic        cpu_awack;
+  logic        cpu_wack;
+  always_comb
+    begin
+      if (cpu_awaddr[11:4] == 1)
+        cpu_wdec = 0;
+      else if (cpu_awaddr[11:8] == 1)
+        cpu_wdec = 1;
+      else
+        cpu_wdec = 2;
+    end
+  always_comb
+    cpu_awready = !cpu_wbusy && cpu_awvalid && cpu_wvalid;
+  always_comb
+    cpu_wready = cpu_awready;
+  always_comb
+    begin
+      uart_awaddr = cpu_waddr[3:0];
+      uart_awvalid = cpu_awpend && cpu_wsel == 0;
+      uart_wdata = cpu_wdata_q;
+      uart_wstrb = cpu_wstrb_q;
+      uart_wvalid = cpu_wpend && cpu_wsel == 0;
+      uart_bready = cpu_wbusy && cpu_wsel == 0 && cpu_bready;
+    end
+  always_comb
+    begin
+      gpio_awaddr = cpu_waddr[7:0];
+      gpio_awvalid = cpu_awpend && cpu_wsel == 1;
+      gpio_wdata = cpu_wdata_q;
+      gpio_wstrb = cpu_wstrb_q;
+      gpio_wvalid = cpu_wpend && cpu_wsel == 1;
+      gpio_bready = cpu_wbusy && cpu_wsel == 1 && cpu_bready;
+    end
+  always_comb
+    begin
+      cpu_awack = 0;
+      cpu_wack = 0;
+      case (cpu_wsel)
+        0: 
+        begin
+          cpu_awack = uart_awready;
+          cpu_wack = uart_wready;
+          cpu_bvalid = cpu_wbusy && uart_bvalid;
+          cpu_bresp = uart_bresp;
+        end
+        1: 
+        begin
+          cpu_awack = gpio_awready;
+          cpu_wack = gpio_wready;
+          cpu_bvalid = cpu_wbusy && gpio_bvalid;
+          cpu_bresp = gpio_bresp;
+        end
+        default: 
+        begin
+          cpu_bvalid = cpu_wbusy;
+          cpu_bresp = 2'b11;
+        end
+      endcase
+    end
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        begin
+          cpu_wbusy <= 0;
+          cpu_wsel <= 0;
+          cpu_waddr <= 0;
+          cpu_wdata_q <= 0;
+          cpu_wstrb_q <= 0;
+          cpu_awpend <= 0;
+          cpu_wpend <= 0;
+        end
+      else
+        begin
+          if (cpu_awready)
+            begin
+              cpu_wbusy <= 1;
+              cpu_wsel <= cpu_wdec;
+              cpu_waddr <= cpu_awaddr;
+              cpu_wdata_q <= cpu_wdata;
+              cpu_wstrb_q <= cpu_wstrb;
+              cpu_awpend <= cpu_wdec != 2;
+              cpu_wpend <= cpu_wdec != 2;
+            end
+          if (cpu_awpend && cpu_awack)
+            cpu_awpend <= 0;
+          if (cpu_wpend && cpu_wack)
+            cpu_wpend <= 0;
+          if (cpu_bvalid && cpu_bready)
+            cpu_wbusy <= 0;
+        end
+    end
+  logic [ 1:0] cpu_rdec;
+  logic        cpu_rbusy;
+  logic [ 1:0] cpu_rsel;
+  logic [11:0] cpu_raddr;
+  logic        cpu_arpend;
+  logic        cpu_arack;
+  always_comb
+    begin
+      if (cpu_araddr[11:4] == 1)
+        cpu_rdec = 0;
+      else if (cpu_araddr[11:8] == 1)
+        cpu_rdec = 1;
+      else
+        cpu_rdec = 2;
+    end
+  always_comb
+    cpu_arready = !cpu_rbusy && cpu_arvalid;
+  always_comb
+    begin
+      uart_araddr = cpu_raddr[3:0];
+      uart_arvalid = cpu_arpend && cpu_rsel == 0;
+      uart_rready = cpu_rbusy && cpu_rsel == 0 && cpu_rready;
+    end
+  always_comb
+    begin
+      gpio_araddr = cpu_raddr[7:0];
+      gpio_arvalid = cpu_arpend && cpu_rsel == 1;
+      gpio_rready = cpu_rbusy && cpu_rsel == 1 && cpu_rready;
+    end
+  always_comb
+    begin
+      cpu_arack = 0;
+      case (cpu_rsel)
+        0: 
+        begin
+          cpu_arack = uart_arready;
+          cpu_rvalid = cpu_rbusy && uart_rvalid;
+          cpu_rdata = uart_rdata;
+          cpu_rresp = uart_rresp;
+        end
+        1: 
+        begin
+          cpu_arack = gpio_arready;
+          cpu_rvalid = cpu_rbusy && gpio_rvalid;
+          cpu_rdata = gpio_rdata;
+          cpu_rresp = gpio_rresp;
+        end
+        default: 
+        begin
+          cpu_rvalid = cpu_rbusy;
+          cpu_rdata = 0;
+          cpu_rresp = 2'b11;
+        end
+      endcase
+    end
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        begin
+          cpu_rbusy <= 0;
+          cpu_rsel <= 0;
+          cpu_raddr <= 0;
+          cpu_arpend <= 0;
+        end
+      else
+        begin
+          if (cpu_arready)
+            begin
+              cpu_rbusy <= 1;
+              cpu_rsel <= cpu_rdec;
+              cpu_raddr <= cpu_araddr;
+              cpu_arpend <= cpu_rdec != 2;
+            end
+          if (cpu_arpend && cpu_arack)
+            cpu_arpend <= 0;
+          if (cpu_rvalid && cpu_rready)
+            cpu_rbusy <= 0;
+        end
+    end
+endmodule
\ No newline at end of file
@@ -0,0 +1,105 @@
+module axi_lite_master
+(
+  input  logic        clk,
+  input  logic        rstn,
+  output logic [31:0] mbus_awaddr,
+  output logic        mbus_awvalid,
+  input  logic        mbus_awready,
+  output logic [31:0] mbus_wdata,
+  output logic [ 3:0] mbus_wstrb,
+  output logic        mbus_wvalid,
+  input  logic        mbus_wready,
+  input  logic [ 1:0] mbus_bresp,
+  input  logic        mbus_bvalid,
+  output logic        mbus_bready,
+  output logic [31:0] mbus_araddr,
+  output logic        mbus_arvalid,
+  input  logic        mbus_arready,
+  input  logic [31:0] mbus_rdata,
+  input  logic [ 1:0] mbus_rresp,
+  input  logic        mbus_rvalid,
+  output logic        mbus_rready
+)
+;
+  logic        mbus_start;
+  logic        mbus_write;
+  logic [31:0] mbus_addr;
+  logic [31:0] mbus_wr_data;
+  logic [ 3:0] mbus_wr_strb;
+  logic        mbus_busy;
+  logic        mbus_done;
+  logic [31:0] mbus_rd_data;
+  logic        mbus_error;
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        begin
+          mbus_awaddr <= 0;
+          mbus_awvalid <= 0;
+          mbus_wdata <= 0;
+          mbus_wstrb <= 0;
+          mbus_wvalid <= 0;
+          mbus_araddr <= 0;
+          mbus_arvalid <= 0;
+        end
+      else
+        begin
+          if (mbus_start && !mbus_busy)
+            begin
+              if (mbus_write)
+                begin
+                  mbus_awaddr <= mbus_addr;
+                  mbus_awvalid <= 1;
+                  mbus_wdata <= mbus_wr_data;
+                  mbus_wstrb <= mbus_wr_strb;
+                  mbus_wvalid <= 1;
+                end
+              else
+                begin
+                  mbus_araddr <= mbus_addr;
+                  mbus_arvalid <= 1;
+                end
+            end
+          if (mbus_awvalid && mbus_awready)
+            mbus_awvalid <= 0;
+          if (mbus_wvalid && mbus_wready)
+            mbus_wvalid <= 0;
+          if (mbus_arvalid && mbus_arready)
+            mbus_arvalid <= 0;
+        end
+    end
+  always_comb
+    begin
+      mbus_bready = mbus_busy;
+      mbus_rready = mbus_busy;
+    end
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        begin
+          mbus_busy <= 0;
+          mbus_done <= 0;
+          mbus_rd_data <= 0;
+          mbus_error <= 0;
+        end
+      else
+        begin
+          mbus_done <= 0;
+          if (mbus_start && !mbus_busy)
+            mbus_busy <= 1;
+          if (mbus_bvalid && mbus_bready)
+            begin
+              mbus_busy <= 0;
+              mbus_done <= 1;
+              mbus_error <= mbus_bresp[1];
+            end
+          if (mbus_rvalid && mbus_rready)
+            begin
+              mbus_busy <= 0;
+              mbus_done <= 1;
+              mbus_rd_data <= mbus_rdata;
+              mbus_error <= mbus_rresp[1];
+            end
+        end
+    end
+endmodule
\ No newline at end of file
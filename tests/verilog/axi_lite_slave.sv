@@ -39,7 +39,7 @@ module axi_lite_slave
         begin
           if (cbus_wvalid && cbus_awvalid)
             begin
-              case (cbus_awaddr)
+              case (cbus_awaddr[7:2])
                 0: 
                 csr_rw[0] <= cbus_wdata[7:0];
                 1: 
@@ -64,7 +64,7 @@ module axi_lite_slave
         begin
           if (cbus_arvalid)
             begin
-              case (cbus_araddr)
+              case (cbus_araddr[7:2])
                 0: 
                 cbus_rdata[7:0] <= csr_rw[0];
                 1: 
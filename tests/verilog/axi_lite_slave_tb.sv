@@ -130,16 +130,16 @@ module tb;
     // Writing to all registers
 
     axi_lite_write(0, 32'hFFFF_FFFF);
-    axi_lite_write(1, 32'hFFFF_FFFF);
-    axi_lite_write(2, 32'hFFFF_FFFF);
-    axi_lite_write(3, 32'hFFFF_FFFF);
+    axi_lite_write(4, 32'hFFFF_FFFF);
+    axi_lite_write(8, 32'hFFFF_FFFF);
+    axi_lite_write(12, 32'hFFFF_FFFF);
 
     // Reading from all registers
 
     axi_lite_read(0, tmp_rdata);
-    axi_lite_read(1, tmp_rdata);
-    axi_lite_read(2, tmp_rdata);
-    axi_lite_read(3, tmp_rdata);
+    axi_lite_read(4, tmp_rdata);
+    axi_lite_read(8, tmp_rdata);
+    axi_lite_read(12, tmp_rdata);
 
     #20;
     $finish;
@@ -0,0 +1,109 @@
+module axi_lite_to_apb
+(
+  input  logic        clk,
+  input  logic        rstn,
+  input  logic [11:0] cbus_awaddr,
+  input  logic        cbus_awvalid,
+  output logic        cbus_awready,
+  input  logic [31:0] cbus_wdata,
+  input  logic [ 3:0] cbus_wstrb,
+  input  logic        cbus_wvalid,
+  output logic        cbus_wready,
+  output logic [ 1:0] cbus_bresp,
+  output logic        cbus_bvalid,
+  input  logic        cbus_bready,
+  input  logic [11:0] cbus_araddr,
+  input  logic        cbus_arvalid,
+  output logic        cbus_arready,
+  output logic [31:0] cbus_rdata,
+  output logic [ 1:0] cbus_rresp,
+  output logic        cbus_rvalid,
+  input  logic        cbus_rready,
+  output logic [11:0] pbus_paddr,
+  output logic        pbus_psel,
+  output logic        pbus_penable,
+  output logic        pbus_pwrite,
+  output logic [ 2:0] pbus_pprot,
+  output logic [31:0] pbus_pwdata,
+  output logic [ 3:0] pbus_pstrb,
+  input  logic [31:0] pbus_prdata,
+  input  logic        pbus_pready,
+  input  logic        pbus_pslverr
+)
+;
+  logic        cbus_busy;
+  always_comb
+    begin
+      cbus_awready = !cbus_busy && cbus_awvalid && cbus_wvalid;
+      cbus_wready = cbus_awready;
+      cbus_arready = !cbus_busy && cbus_arvalid && !cbus_awready;
+    end
+  always_comb
+    pbus_pprot = 0;
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        begin
+          cbus_busy <= 0;
+          cbus_bvalid <= 0;
+          cbus_bresp <= 0;
+          cbus_rvalid <= 0;
+          cbus_rdata <= 0;
+          cbus_rresp <= 0;
+          pbus_paddr <= 0;
+          pbus_psel <= 0;
+          pbus_penable <= 0;
+          pbus_pwrite <= 0;
+          pbus_pwdata <= 0;
+          pbus_pstrb <= 0;
+        end
+      else
+        begin
+          if (cbus_awready)
+            begin
+              cbus_busy <= 1;
+              pbus_paddr <= cbus_awaddr;
+              pbus_psel <= 1;
+              pbus_pwrite <= 1;
+              pbus_pwdata <= cbus_wdata;
+              pbus_pstrb <= cbus_wstrb;
+            end
+          else if (cbus_arready)
+            begin
+              cbus_busy <= 1;
+              pbus_paddr <= cbus_araddr;
+              pbus_psel <= 1;
+              pbus_pwrite <= 0;
+              pbus_pstrb <= 0;
+            end
+          else if (pbus_psel && !pbus_penable)
+            pbus_penable <= 1;
+          else if (pbus_psel && pbus_penable && pbus_pready)
+            begin
+              pbus_psel <= 0;
+              pbus_penable <= 0;
+              if (pbus_pwrite)
+                begin
+                  cbus_bvalid <= 1;
+                  cbus_bresp <= pbus_pslverr ? 2'b10 : 2'b00;
+                end
+              else
+                begin
+                  cbus_rvalid <= 1;
+                  cbus_rdata <= pbus_prdata;
+                  cbus_rresp <= pbus_pslverr ? 2'b10 : 2'b00;
+                end
+            end
+          if (cbus_bvalid && cbus_bready)
+            begin
+              cbus_bvalid <= 0;
+              cbus_busy <= 0;
+            end
+          if (cbus_rvalid && cbus_rready)
+            begin
+              cbus_rvalid <= 0;
+              cbus_busy <= 0;
+            end
+        end
+    end
+endmodule
\ No newline at end of file
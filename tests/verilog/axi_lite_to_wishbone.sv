@@ -0,0 +1,106 @@
+module axi_lite_to_wishbone
+(
+  input  logic        clk,
+  input  logic        rstn,
+  input  logic [11:0] cbus_awaddr,
+  input  logic        cbus_awvalid,
+  output logic        cbus_awready,
+  input  logic [31:0] cbus_wdata,
+  input  logic [ 3:0] cbus_wstrb,
+  input  logic        cbus_wvalid,
+  output logic        cbus_wready,
+  output logic [ 1:0] cbus_bresp,
+  output logic        cbus_bvalid,
+  input  logic        cbus_bready,
+  input  logic [11:0] cbus_araddr,
+  input  logic        cbus_arvalid,
+  output logic        cbus_arready,
+  output logic [31:0] cbus_rdata,
+  output logic [ 1:0] cbus_rresp,
+  output logic        cbus_rvalid,
+  input  logic        cbus_rready,
+  output logic        wbus_cyc,
+  output logic        wbus_stb,
+  output logic        wbus_we,
+  output logic [ 3:0] wbus_sel,
+  output logic [11:0] wbus_adr,
+  output logic [31:0] wbus_dat_w,
+  input  logic [31:0] wbus_dat_r,
+  input  logic        wbus_ack,
+  input  logic        wbus_err
+)
+;
+  logic        cbus_busy;
+  always_comb
+    begin
+      cbus_awready = !cbus_busy && cbus_awvalid && cbus_wvalid;
+      cbus_wready = cbus_awready;
+      cbus_arready = !cbus_busy && cbus_arvalid && !cbus_awready;
+    end
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        begin
+          cbus_busy <= 0;
+          cbus_bvalid <= 0;
+          cbus_bresp <= 0;
+          cbus_rvalid <= 0;
+          cbus_rdata <= 0;
+          cbus_rresp <= 0;
+          wbus_cyc <= 0;
+          wbus_stb <= 0;
+          wbus_we <= 0;
+          wbus_sel <= 0;
+          wbus_adr <= 0;
+          wbus_dat_w <= 0;
+        end
+      else
+        begin
+          if (cbus_awready)
+            begin
+              cbus_busy <= 1;
+              wbus_cyc <= 1;
+              wbus_stb <= 1;
+              wbus_we <= 1;
+              wbus_sel <= cbus_wstrb;
+              wbus_adr <= cbus_awaddr;
+              wbus_dat_w <= cbus_wdata;
+            end
+          else if (cbus_arready)
+            begin
+              cbus_busy <= 1;
+              wbus_cyc <= 1;
+              wbus_stb <= 1;
+              wbus_we <= 0;
+              wbus_sel <= '1;
+              wbus_adr <= cbus_araddr;
+            end
+          else if (wbus_cyc && (wbus_ack || wbus_err))
+            begin
+              wbus_cyc <= 0;
+              wbus_stb <= 0;
+              if (wbus_we)
+                begin
+                  cbus_bvalid <= 1;
+                  cbus_bresp <= wbus_err ? 2'b10 : 2'b00;
+                end
+              else
+                begin
+                  cbus_rvalid <= 1;
+                  cbus_rdata <= wbus_dat_r;
+                  cbus_rresp <= wbus_err ? 2'b10 : 2'b00;
+                end
+            end
+          if (cbus_bvalid && cbus_bready)
+            begin
+              cbus_bvalid <= 0;
+              cbus_busy <= 0;
+            end
+          if (cbus_rvalid && cbus_rready)
+            begin
+              cbus_rvalid <= 0;
+              cbus_busy <= 0;
+            end
+        end
+    end
+endmodule
\ No newline at end of file
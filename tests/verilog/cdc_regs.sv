@@ -0,0 +1,411 @@
+module cdc_regs
+(
+  input  logic        bus_clk,
+  input  logic        bus_rstn,
+  input  logic        core_clk,
+  input  logic        core_rstn,
+  input  logic [ 7:0] cbus_awaddr,
+  input  logic        cbus_awvalid,
+  output logic        cbus_awready,
+  input  logic [31:0] cbus_wdata,
+  input  logic [ 3:0] cbus_wstrb,
+  input  logic        cbus_wvalid,
+  output logic        cbus_wready,
+  output logic [ 1:0] cbus_bresp,
+  output logic        cbus_bvalid,
+  input  logic        cbus_bready,
+  input  logic [ 7:0] cbus_araddr,
+  input  logic        cbus_arvalid,
+  output logic        cbus_arready,
+  output logic [31:0] cbus_rdata,
+  output logic [ 1:0] cbus_rresp,
+  output logic        cbus_rvalid,
+  input  logic        cbus_rready
+)
+;
+  logic [ 7:0] csr_rw[ 1:0];
+  logic [ 7:0] csr_ro;
+  logic        csr_tw_trig;
+  logic [ 7:0] csr_rw_core[ 1:0];
+  logic        csr_rw_cdc0_send;
+  logic [ 7:0] csr_rw_cdc0_data;
+  logic        csr_rw_cdc0_busy;
+  logic [ 7:0] csr_rw_cdc0_hold;
+  logic        csr_rw_cdc0_req;
+  logic [ 7:0] csr_rw_cdc0_out;
+  logic        csr_rw_cdc0_valid;
+  logic        csr_rw_cdc0_ack;
+  (* ASYNC_REG = "TRUE" *) logic        csr_rw_cdc0_ack_s1;
+  (* ASYNC_REG = "TRUE" *) logic        csr_rw_cdc0_ack_s2;
+  always_ff @(posedge bus_clk)
+    begin
+      if (!bus_rstn)
+        begin
+          csr_rw_cdc0_ack_s1 <= 0;
+          csr_rw_cdc0_ack_s2 <= 0;
+        end
+      else
+        begin
+          csr_rw_cdc0_ack_s1 <= csr_rw_cdc0_ack;
+          csr_rw_cdc0_ack_s2 <= csr_rw_cdc0_ack_s1;
+        end
+    end
+  always_ff @(posedge bus_clk)
+    begin
+      if (!bus_rstn)
+        begin
+          csr_rw_cdc0_busy <= 0;
+          csr_rw_cdc0_hold <= 0;
+          csr_rw_cdc0_req <= 0;
+        end
+      else
+        begin
+          if (csr_rw_cdc0_send && !csr_rw_cdc0_busy)
+            begin
+              csr_rw_cdc0_hold <= csr_rw_cdc0_data;
+              csr_rw_cdc0_req <= !csr_rw_cdc0_req;
+              csr_rw_cdc0_busy <= 1;
+            end
+          else if (csr_rw_cdc0_busy && csr_rw_cdc0_ack_s2 == csr_rw_cdc0_req)
+            csr_rw_cdc0_busy <= 0;
+        end
+    end
+  (* ASYNC_REG = "TRUE" *) logic        csr_rw_cdc0_req_s1;
+  (* ASYNC_REG = "TRUE" *) logic        csr_rw_cdc0_req_s2;
+  always_ff @(posedge core_clk)
+    begin
+      if (!core_rstn)
+        begin
+          csr_rw_cdc0_req_s1 <= 0;
+          csr_rw_cdc0_req_s2 <= 0;
+        end
+      else
+        begin
+          csr_rw_cdc0_req_s1 <= csr_rw_cdc0_req;
+          csr_rw_cdc0_req_s2 <= csr_rw_cdc0_req_s1;
+        end
+    end
+  always_ff @(posedge core_clk)
+    begin
+      if (!core_rstn)
+        begin
+          csr_rw_cdc0_out <= 0;
+          csr_rw_cdc0_valid <= 0;
+          csr_rw_cdc0_ack <= 0;
+        end
+      else
+        begin
+          csr_rw_cdc0_valid <= 0;
+          if (csr_rw_cdc0_req_s2 != csr_rw_cdc0_ack)
+            begin
+              csr_rw_cdc0_out <= csr_rw_cdc0_hold;
+              csr_rw_cdc0_valid <= 1;
+              csr_rw_cdc0_ack <= csr_rw_cdc0_req_s2;
+            end
+        end
+    end
+  always_comb
+    begin
+      csr_rw_cdc0_data = csr_rw[0];
+      csr_rw_cdc0_send = csr_rw[0] != csr_rw_cdc0_hold;
+    end
+  always_ff @(posedge core_clk)
+    begin
+      if (!core_rstn)
+        csr_rw_core[0] <= 0;
+      else
+        begin
+          if (csr_rw_cdc0_valid)
+            csr_rw_core[0] <= csr_rw_cdc0_out;
+        end
+    end
+  logic        csr_rw_cdc1_send;
+  logic [ 7:0] csr_rw_cdc1_data;
+  logic        csr_rw_cdc1_busy;
+  logic [ 7:0] csr_rw_cdc1_hold;
+  logic        csr_rw_cdc1_req;
+  logic [ 7:0] csr_rw_cdc1_out;
+  logic        csr_rw_cdc1_valid;
+  logic        csr_rw_cdc1_ack;
+  (* ASYNC_REG = "TRUE" *) logic        csr_rw_cdc1_ack_s1;
+  (* ASYNC_REG = "TRUE" *) logic        csr_rw_cdc1_ack_s2;
+  always_ff @(posedge bus_clk)
+    begin
+      if (!bus_rstn)
+        begin
+          csr_rw_cdc1_ack_s1 <= 0;
+          csr_rw_cdc1_ack_s2 <= 0;
+        end
+      else
+        begin
+          csr_rw_cdc1_ack_s1 <= csr_rw_cdc1_ack;
+          csr_rw_cdc1_ack_s2 <= csr_rw_cdc1_ack_s1;
+        end
+    end
+  always_ff @(posedge bus_clk)
+    begin
+      if (!bus_rstn)
+        begin
+          csr_rw_cdc1_busy <= 0;
+          csr_rw_cdc1_hold <= 0;
+          csr_rw_cdc1_req <= 0;
+        end
+      else
+        begin
+          if (csr_rw_cdc1_send && !csr_rw_cdc1_busy)
+            begin
+              csr_rw_cdc1_hold <= csr_rw_cdc1_data;
+              csr_rw_cdc1_req <= !csr_rw_cdc1_req;
+              csr_rw_cdc1_busy <= 1;
+            end
+          else if (csr_rw_cdc1_busy && csr_rw_cdc1_ack_s2 == csr_rw_cdc1_req)
+            csr_rw_cdc1_busy <= 0;
+        end
+    end
+  (* ASYNC_REG = "TRUE" *) logic        csr_rw_cdc1_req_s1;
+  (* ASYNC_REG = "TRUE" *) logic        csr_rw_cdc1_req_s2;
+  always_ff @(posedge core_clk)
+    begin
+      if (!core_rstn)
+        begin
+          csr_rw_cdc1_req_s1 <= 0;
+          csr_rw_cdc1_req_s2 <= 0;
+        end
+      else
+        begin
+          csr_rw_cdc1_req_s1 <= csr_rw_cdc1_req;
+          csr_rw_cdc1_req_s2 <= csr_rw_cdc1_req_s1;
+        end
+    end
+  always_ff @(posedge core_clk)
+    begin
+      if (!core_rstn)
+        begin
+          csr_rw_cdc1_out <= 0;
+          csr_rw_cdc1_valid <= 0;
+          csr_rw_cdc1_ack <= 0;
+        end
+      else
+        begin
+          csr_rw_cdc1_valid <= 0;
+          if (csr_rw_cdc1_req_s2 != csr_rw_cdc1_ack)
+            begin
+              csr_rw_cdc1_out <= csr_rw_cdc1_hold;
+              csr_rw_cdc1_valid <= 1;
+              csr_rw_cdc1_ack <= csr_rw_cdc1_req_s2;
+            end
+        end
+    end
+  always_comb
+    begin
+      csr_rw_cdc1_data = csr_rw[1];
+      csr_rw_cdc1_send = csr_rw[1] != csr_rw_cdc1_hold;
+    end
+  always_ff @(posedge core_clk)
+    begin
+      if (!core_rstn)
+        csr_rw_core[1] <= 0;
+      else
+        begin
+          if (csr_rw_cdc1_valid)
+            csr_rw_core[1] <= csr_rw_cdc1_out;
+        end
+    end
+  logic [ 7:0] csr_ro_core;
+  logic        csr_ro_cdc_send;
+  logic [ 7:0] csr_ro_cdc_data;
+  logic        csr_ro_cdc_busy;
+  logic [ 7:0] csr_ro_cdc_hold;
+  logic        csr_ro_cdc_req;
+  logic [ 7:0] csr_ro_cdc_out;
+  logic        csr_ro_cdc_valid;
+  logic        csr_ro_cdc_ack;
+  (* ASYNC_REG = "TRUE" *) logic        csr_ro_cdc_ack_s1;
+  (* ASYNC_REG = "TRUE" *) logic        csr_ro_cdc_ack_s2;
+  always_ff @(posedge core_clk)
+    begin
+      if (!core_rstn)
+        begin
+          csr_ro_cdc_ack_s1 <= 0;
+          csr_ro_cdc_ack_s2 <= 0;
+        end
+      else
+        begin
+          csr_ro_cdc_ack_s1 <= csr_ro_cdc_ack;
+          csr_ro_cdc_ack_s2 <= csr_ro_cdc_ack_s1;
+        end
+    end
+  always_ff @(posedge core_clk)
+    begin
+      if (!core_rstn)
+        begin
+          csr_ro_cdc_busy <= 0;
+          csr_ro_cdc_hold <= 0;
+          csr_ro_cdc_req <= 0;
+        end
+      else
+        begin
+          if (csr_ro_cdc_send && !csr_ro_cdc_busy)
+            begin
+              csr_ro_cdc_hold <= csr_ro_cdc_data;
+              csr_ro_cdc_req <= !csr_ro_cdc_req;
+              csr_ro_cdc_busy <= 1;
+            end
+          else if (csr_ro_cdc_busy && csr_ro_cdc_ack_s2 == csr_ro_cdc_req)
+            csr_ro_cdc_busy <= 0;
+        end
+    end
+  (* ASYNC_REG = "TRUE" *) logic        csr_ro_cdc_req_s1;
+  (* ASYNC_REG = "TRUE" *) logic        csr_ro_cdc_req_s2;
+  always_ff @(posedge bus_clk)
+    begin
+      if (!bus_rstn)
+        begin
+          csr_ro_cdc_req_s1 <= 0;
+          csr_ro_cdc_req_s2 <= 0;
+        end
+      else
+        begin
+          csr_ro_cdc_req_s1 <= csr_ro_cdc_req;
+          csr_ro_cdc_req_s2 <= csr_ro_cdc_req_s1;
+        end
+    end
+  always_ff @(posedge bus_clk)
+    begin
+      if (!bus_rstn)
+        begin
+          csr_ro_cdc_out <= 0;
+          csr_ro_cdc_valid <= 0;
+          csr_ro_cdc_ack <= 0;
+        end
+      else
+        begin
+          csr_ro_cdc_valid <= 0;
+          if (csr_ro_cdc_req_s2 != csr_ro_cdc_ack)
+            begin
+              csr_ro_cdc_out <= csr_ro_cdc_hold;
+              csr_ro_cdc_valid <= 1;
+              csr_ro_cdc_ack <= csr_ro_cdc_req_s2;
+            end
+        end
+    end
+  always_comb
+    begin
+      csr_ro_cdc_data = csr_ro_core;
+      csr_ro_cdc_send = csr_ro_core != csr_ro_cdc_hold;
+    end
+  always_ff @(posedge bus_clk)
+    begin
+      if (!bus_rstn)
+        csr_ro <= 0;
+      else
+        begin
+          if (csr_ro_cdc_valid)
+            csr_ro <= csr_ro_cdc_out;
+        end
+    end
+  logic        csr_tw_resp_core;
+  (* ASYNC_REG = "TRUE" *) logic        csr_tw_trig_core_s1;
+  (* ASYNC_REG = "TRUE" *) logic        csr_tw_trig_core;
+  always_ff @(posedge core_clk)
+    begin
+      if (!core_rstn)
+        begin
+          csr_tw_trig_core_s1 <= 0;
+          csr_tw_trig_core <= 0;
+        end
+      else
+        begin
+          csr_tw_trig_core_s1 <= csr_tw_trig;
+          csr_tw_trig_core <= csr_tw_trig_core_s1;
+        end
+    end
+  (* ASYNC_REG = "TRUE" *) logic        csr_tw_resp_s1;
+  (* ASYNC_REG = "TRUE" *) logic        csr_tw_resp;
+  always_ff @(posedge bus_clk)
+    begin
+      if (!bus_rstn)
+        begin
+          csr_tw_resp_s1 <= 0;
+          csr_tw_resp <= 0;
+        end
+      else
+        begin
+          csr_tw_resp_s1 <= csr_tw_resp_core;
+          csr_tw_resp <= csr_tw_resp_s1;
+        end
+    end
+  always_ff @(posedge bus_clk)
+    begin
+      if (!bus_rstn)
+        begin
+          csr_rw[0] <= 0;
+          csr_rw[1] <= 0;
+          csr_tw_trig <= 0;
+        end
+      else
+        begin
+          if (cbus_wvalid && cbus_awvalid)
+            begin
+              case (cbus_awaddr[7:2])
+                0: 
+                csr_rw[0] <= cbus_wdata[7:0];
+                1: 
+                csr_rw[1] <= cbus_wdata[7:0];
+                3: 
+                csr_tw_trig <= cbus_wdata[0:0];
+                default: 
+                ;
+              endcase
+            end
+        end
+    end
+  always_ff @(posedge bus_clk)
+    begin
+      if (!bus_rstn)
+        cbus_rdata <= 0;
+      else
+        begin
+          if (cbus_arvalid)
+            begin
+              case (cbus_araddr[7:2])
+                0: 
+                cbus_rdata[7:0] <= csr_rw[0];
+                1: 
+                cbus_rdata[7:0] <= csr_rw[1];
+                2: 
+                cbus_rdata[7:0] <= csr_ro;
+                3: 
+                cbus_rdata[0:0] <= csr_tw_resp;
+                default: 
+                cbus_rdata <= 0;
+              endcase
+            end
+        end
+    end
+  always_ff @(posedge bus_clk)
+    begin
+      if (!bus_rstn)
+        begin
+          cbus_awready <= 0;
+          cbus_wready <= 0;
+          cbus_bvalid <= 0;
+          cbus_arready <= 0;
+          cbus_rvalid <= 0;
+          cbus_bresp <= 0;
+          cbus_rresp <= 0;
+        end
+      else
+        begin
+          cbus_awready <= cbus_awvalid && !cbus_awready;
+          cbus_wready <= cbus_wvalid && !cbus_wready;
+          cbus_bvalid <= cbus_awready && cbus_wready && !cbus_bvalid;
+          cbus_arready <= cbus_arvalid && !cbus_arready;
+          cbus_rvalid <= cbus_arvalid && !cbus_arready;
+          if (cbus_bvalid && cbus_bready)
+            cbus_bvalid <= 0;
+          if (cbus_rvalid && cbus_rready)
+            cbus_rvalid <= 0;
+        end
+    end
+endmodule
\ No newline at end of file
@@ -0,0 +1,29 @@
+module cros_domain
+(
+  input  logic        clk_a,
+  input  logic        rstn_a,
+  input  logic        clk_b,
+  input  logic        rstn_b,
+  (* domain = "A" *) input  logic [ 7:0] a,
+  (* domain = "B" *) output logic [ 7:0] b
+)
+;
+  (* domain = "A" *) logic [ 7:0] a_q;
+  (* domain = "B" *) logic [ 7:0] b_q;
+  always_ff @(posedge clk_a)
+    begin
+      if (!rstn_a)
+        a_q <= 0;
+      else
+        a_q <= a;
+    end
+  always_ff @(posedge clk_b)
+    begin
+      if (!rstn_b)
+        b_q <= 0;
+      else
+        b_q <= a_q;
+    end
+  always_comb
+    b = b_q;
+endmodule
\ No newline at end of file
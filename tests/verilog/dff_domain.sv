@@ -0,0 +1,21 @@
+module dff_domain
+(
+  input  logic        clk,
+  input  logic        rstn,
+  input  logic        clk_n,
+  input  logic        rst,
+  input  logic [ 7:0] in0,
+  output logic [ 7:0] out0,
+  output logic [ 7:0] out1
+)
+;
+  always_ff @(negedge clk_n or posedge rst)
+    begin
+      if (rst)
+        out0 <= 0;
+      else
+        out0 <= in0;
+    end
+  always_ff @(posedge clk)
+    out1 <= out0;
+endmodule
\ No newline at end of file
@@ -0,0 +1,21 @@
+module dff_enable
+(
+  input  logic        clk,
+  input  logic        rstn,
+  input  logic        en,
+  input  logic [ 7:0] in0,
+  output logic [ 7:0] count,
+  output logic [ 7:0] data
+)
+;
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        count <= 0;
+      else if (en)
+        count <= count + 1;
+    end
+  always_ff @(posedge clk)
+    if (en)
+      data <= in0;
+endmodule
\ No newline at end of file
@@ -1,6 +1,61 @@
 module fifo
 ;
+  logic        rx_push;
+  logic [ 7:0] rx_wdata;
+  logic        rx_pop;
+  logic [ 7:0] rx_rdata;
+  logic        rx_full;
+  logic        rx_empty;
+  logic [ 5:0] rx_count;
+  logic        rx_overflow;
+  logic        rx_underflow;
   logic [ 7:0] rx_buf[31:0];
   logic [ 4:0] rx_rptr;
   logic [ 4:0] rx_wptr;
+  logic        rx_we;
+  logic        rx_re;
+  always_comb
+    begin
+      rx_full = rx_count == 32;
+      rx_empty = rx_count == 0;
+      rx_we = rx_push && !rx_full;
+      rx_re = rx_pop && !rx_empty;
+    end
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        begin
+          rx_wptr <= 0;
+          rx_rptr <= 0;
+          rx_count <= 0;
+          rx_overflow <= 0;
+          rx_underflow <= 0;
+        end
+      else
+        begin
+          if (rx_we)
+            rx_wptr <= rx_wptr + 1;
+          if (rx_re)
+            rx_rptr <= rx_rptr + 1;
+          if (rx_we && !rx_re)
+            rx_count <= rx_count + 1;
+          else if (!rx_we && rx_re)
+            rx_count <= rx_count - 1;
+          rx_overflow <= rx_push && rx_full;
+          rx_underflow <= rx_pop && rx_empty;
+        end
+    end
+  always_ff @(posedge clk)
+    if (rx_we)
+      rx_buf[rx_wptr] <= rx_wdata;
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        rx_rdata <= 0;
+      else
+        begin
+          if (rx_re)
+            rx_rdata <= rx_buf[rx_rptr];
+        end
+    end
 endmodule
\ No newline at end of file
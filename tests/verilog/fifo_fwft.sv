@@ -0,0 +1,57 @@
+module fifo_fwft
+;
+  logic        rx_push;
+  logic [ 7:0] rx_wdata;
+  logic        rx_pop;
+  logic [ 7:0] rx_rdata;
+  logic        rx_full;
+  logic        rx_empty;
+  logic [ 4:0] rx_count;
+  logic        rx_overflow;
+  logic        rx_underflow;
+  logic [ 7:0] rx_buf[23:0];
+  logic [ 4:0] rx_rptr;
+  logic [ 4:0] rx_wptr;
+  logic        rx_we;
+  logic        rx_re;
+  logic        rx_almost_full;
+  logic        rx_almost_empty;
+  always_comb
+    begin
+      rx_full = rx_count == 24;
+      rx_empty = rx_count == 0;
+      rx_we = rx_push && !rx_full;
+      rx_re = rx_pop && !rx_empty;
+      rx_almost_full = rx_count >= 20;
+      rx_almost_empty = rx_count <= 4;
+    end
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        begin
+          rx_wptr <= 0;
+          rx_rptr <= 0;
+          rx_count <= 0;
+          rx_overflow <= 0;
+          rx_underflow <= 0;
+        end
+      else
+        begin
+          if (rx_we)
+            rx_wptr <= rx_wptr == 23 ? 0 : rx_wptr + 1;
+          if (rx_re)
+            rx_rptr <= rx_rptr == 23 ? 0 : rx_rptr + 1;
+          if (rx_we && !rx_re)
+            rx_count <= rx_count + 1;
+          else if (!rx_we && rx_re)
+            rx_count <= rx_count - 1;
+          rx_overflow <= rx_push && rx_full;
+          rx_underflow <= rx_pop && rx_empty;
+        end
+    end
+  always_ff @(posedge clk)
+    if (rx_we)
+      rx_buf[rx_wptr] <= rx_wdata;
+  always_comb
+    rx_rdata = rx_buf[rx_rptr];
+endmodule
\ No newline at end of file
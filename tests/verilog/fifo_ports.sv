@@ -0,0 +1,67 @@
+module fifo_ports
+(
+  input  logic        clk,
+  input  logic        rstn,
+  input  logic        tx_push,
+  input  logic [ 7:0] tx_wdata,
+  input  logic        tx_pop,
+  output logic [ 7:0] tx_rdata,
+  output logic        tx_full,
+  output logic        tx_empty,
+  output logic [ 4:0] tx_count,
+  output logic        tx_overflow,
+  output logic        tx_underflow,
+  output logic        tx_almost_full
+)
+;
+  logic [ 7:0] tx_buf[15:0];
+  logic [ 3:0] tx_rptr;
+  logic [ 3:0] tx_wptr;
+  logic        tx_we;
+  logic        tx_re;
+  always_comb
+    begin
+      tx_full = tx_count == 16;
+      tx_empty = tx_count == 0;
+      tx_we = tx_push && !tx_full;
+      tx_re = tx_pop && !tx_empty;
+      tx_almost_full = tx_count >= 12;
+    end
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        begin
+          tx_wptr <= 0;
+          tx_rptr <= 0;
+          tx_count <= 0;
+          tx_overflow <= 0;
+          tx_underflow <= 0;
+        end
+      else
+        begin
+          if (tx_we)
+            tx_wptr <= tx_wptr + 1;
+          if (tx_re)
+            tx_rptr <= tx_rptr + 1;
+          if (tx_we && !tx_re)
+            tx_count <= tx_count + 1;
+          else if (!tx_we && tx_re)
+            tx_count <= tx_count - 1;
+          tx_overflow <= tx_push && tx_full;
+          tx_underflow <= tx_pop && tx_empty;
+        end
+    end
+  always_ff @(posedge clk)
+    if (tx_we)
+      tx_buf[tx_wptr] <= tx_wdata;
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        tx_rdata <= 0;
+      else
+        begin
+          if (tx_re)
+            tx_rdata <= tx_buf[tx_rptr];
+        end
+    end
+endmodule
\ No newline at end of file
@@ -0,0 +1,111 @@
+module hw_write
+(
+  input  logic        clk,
+  input  logic        rstn,
+  input  logic [ 7:0] cbus_awaddr,
+  input  logic        cbus_awvalid,
+  output logic        cbus_awready,
+  input  logic [31:0] cbus_wdata,
+  input  logic [ 3:0] cbus_wstrb,
+  input  logic        cbus_wvalid,
+  output logic        cbus_wready,
+  output logic [ 1:0] cbus_bresp,
+  output logic        cbus_bvalid,
+  input  logic        cbus_bready,
+  input  logic [ 7:0] cbus_araddr,
+  input  logic        cbus_arvalid,
+  output logic        cbus_arready,
+  output logic [31:0] cbus_rdata,
+  output logic [ 1:0] cbus_rresp,
+  output logic        cbus_rvalid,
+  input  logic        cbus_rready
+)
+;
+  logic [ 7:0] ctrl;
+  logic        flag[ 1:0];
+  logic        flag_hw_we[ 1:0];
+  logic        flag_hw_d[ 1:0];
+  logic [15:0] count;
+  logic        count_hw_we;
+  logic [15:0] count_hw_d;
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        begin
+          ctrl <= 0;
+          flag[0] <= 0;
+          flag[1] <= 0;
+          count <= 0;
+        end
+      else
+        begin
+          if (cbus_wvalid && cbus_awvalid)
+            begin
+              case (cbus_awaddr[7:2])
+                0: 
+                ctrl <= cbus_wdata[7:0];
+                1: 
+                flag[0] <= cbus_wdata[0:0];
+                2: 
+                flag[1] <= cbus_wdata[0:0];
+                default: 
+                ;
+              endcase
+            end
+          if (flag_hw_we[0])
+            flag[0] <= flag_hw_d[0];
+          if (flag_hw_we[1])
+            flag[1] <= flag_hw_d[1];
+          if (count_hw_we)
+            count <= count_hw_d;
+        end
+    end
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        cbus_rdata <= 0;
+      else
+        begin
+          if (cbus_arvalid)
+            begin
+              case (cbus_araddr[7:2])
+                0: 
+                cbus_rdata[7:0] <= ctrl;
+                1: 
+                cbus_rdata[0:0] <= flag[0];
+                2: 
+                cbus_rdata[0:0] <= flag[1];
+                3: 
+                cbus_rdata[15:0] <= count;
+                default: 
+                cbus_rdata <= 0;
+              endcase
+            end
+        end
+    end
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        begin
+          cbus_awready <= 0;
+          cbus_wready <= 0;
+          cbus_bvalid <= 0;
+          cbus_arready <= 0;
+          cbus_rvalid <= 0;
+          cbus_bresp <= 0;
+          cbus_rresp <= 0;
+        end
+      else
+        begin
+          cbus_awready <= cbus_awvalid && !cbus_awready;
+          cbus_wready <= cbus_wvalid && !cbus_wready;
+          cbus_bvalid <= cbus_awready && cbus_wready && !cbus_bvalid;
+          cbus_arready <= cbus_arvalid && !cbus_arready;
+          cbus_rvalid <= cbus_arvalid && !cbus_arready;
+          if (cbus_bvalid && cbus_bready)
+            cbus_bvalid <= 0;
+          if (cbus_rvalid && cbus_rready)
+            cbus_rvalid <= 0;
+        end
+    end
+endmodule
\ No newline at end of file
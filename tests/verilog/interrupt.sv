@@ -0,0 +1,110 @@
+module interrupt
+(
+  input  logic        clk,
+  input  logic        rstn,
+  input  logic [ 7:0] cbus_awaddr,
+  input  logic        cbus_awvalid,
+  output logic        cbus_awready,
+  input  logic [31:0] cbus_wdata,
+  input  logic [ 3:0] cbus_wstrb,
+  input  logic        cbus_wvalid,
+  output logic        cbus_wready,
+  output logic [ 1:0] cbus_bresp,
+  output logic        cbus_bvalid,
+  input  logic        cbus_bready,
+  input  logic [ 7:0] cbus_araddr,
+  input  logic        cbus_arvalid,
+  output logic        cbus_arready,
+  output logic [31:0] cbus_rdata,
+  output logic [ 1:0] cbus_rresp,
+  output logic        cbus_rvalid,
+  input  logic        cbus_rready,
+  output logic        cbus_irq
+)
+;
+  logic [ 7:0] ctrl;
+  logic [ 7:0] status;
+  logic        error;
+  logic [ 2:0] int_status;
+  logic [ 2:0] int_enable;
+  always_comb
+    cbus_irq = |(int_status & int_enable);
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        begin
+          ctrl <= 0;
+          int_status <= 0;
+          int_enable <= 0;
+        end
+      else
+        begin
+          int_status <= int_status | {error, status[1], status[0]};
+          if (cbus_wvalid && cbus_awvalid)
+            begin
+              case (cbus_awaddr[7:2])
+                0: 
+                ctrl <= cbus_wdata[7:0];
+                3: 
+                int_status <= (int_status & ~cbus_wdata[2:0]) | {error, status[1], status[0]};
+                4: 
+                int_enable <= cbus_wdata[2:0];
+                5: 
+                int_status <= int_status | cbus_wdata[2:0] | {error, status[1], status[0]};
+                default: 
+                ;
+              endcase
+            end
+        end
+    end
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        cbus_rdata <= 0;
+      else
+        begin
+          if (cbus_arvalid)
+            begin
+              case (cbus_araddr[7:2])
+                0: 
+                cbus_rdata[7:0] <= ctrl;
+                1: 
+                cbus_rdata[7:0] <= status;
+                2: 
+                cbus_rdata[0:0] <= error;
+                3: 
+                cbus_rdata[2:0] <= int_status;
+                4: 
+                cbus_rdata[2:0] <= int_enable;
+                default: 
+                cbus_rdata <= 0;
+              endcase
+            end
+        end
+    end
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        begin
+          cbus_awready <= 0;
+          cbus_wready <= 0;
+          cbus_bvalid <= 0;
+          cbus_arready <= 0;
+          cbus_rvalid <= 0;
+          cbus_bresp <= 0;
+          cbus_rresp <= 0;
+        end
+      else
+        begin
+          cbus_awready <= cbus_awvalid && !cbus_awready;
+          cbus_wready <= cbus_wvalid && !cbus_wready;
+          cbus_bvalid <= cbus_awready && cbus_wready && !cbus_bvalid;
+          cbus_arready <= cbus_arvalid && !cbus_arready;
+          cbus_rvalid <= cbus_arvalid && !cbus_arready;
+          if (cbus_bvalid && cbus_bready)
+            cbus_bvalid <= 0;
+          if (cbus_rvalid && cbus_rready)
+            cbus_rvalid <= 0;
+        end
+    end
+endmodule
\ No newline at end of file
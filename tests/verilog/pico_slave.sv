@@ -1,44 +1,89 @@
-module pico_slave (
-    input  logic        clk,
-    input  logic        rstn,
-    input  logic        valid,
-    input  logic        ready,
-    input  logic [ 3:0] wstrb,
-    input  logic [ 7:0] addr,
-    input  logic [31:0] wdata,
-    output logic [31:0] rdata
-);
-  logic [7:0] csr_rw      [3:0];
-  logic [7:0] csr_ro;
-  logic       csr_tw_trig;
-  logic       csr_tw_resp;
-  always_ff @(posedge clk) begin
-    if (!rstn) begin
-      csr_rw[0]   <= 0;
-      csr_rw[1]   <= 0;
-      csr_rw[2]   <= 0;
-      csr_rw[3]   <= 0;
-      csr_tw_trig <= 0;
-    end else begin
-      case (addr)
-        0: csr_rw[0] <= wdata[7:0];
-        1: csr_rw[1] <= wdata[7:0];
-        2: csr_rw[2] <= wdata[7:0];
-        3: csr_rw[3] <= wdata[7:0];
-        5: csr_tw_trig <= wdata[0:0];
-        default: ;
-      endcase
+module pico_slave
+(
+  input  logic        clk,
+  input  logic        rstn,
+  input  logic        mem_valid,
+  output logic        mem_ready,
+  input  logic [ 7:0] mem_addr,
+  input  logic [ 3:0] mem_wstrb,
+  input  logic [31:0] mem_wdata,
+  output logic [31:0] mem_rdata
+)
+;
+  logic [ 7:0] csr_rw[ 3:0];
+  logic [ 7:0] csr_ro;
+  logic        csr_tw_trig;
+  logic        csr_tw_resp;
+  logic        mem_req;
+  always_comb
+    mem_req = mem_valid && !mem_ready;
+  logic [31:0] mem_wmask;
+  always_comb
+    mem_wmask = {{8{mem_wstrb[3]}}, {8{mem_wstrb[2]}}, {8{mem_wstrb[1]}}, {8{mem_wstrb[0]}}};
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        begin
+          csr_rw[0] <= 0;
+          csr_rw[1] <= 0;
+          csr_rw[2] <= 0;
+          csr_rw[3] <= 0;
+          csr_tw_trig <= 0;
+        end
+      else
+        begin
+          if (mem_req && |mem_wstrb)
+            begin
+              case (mem_addr[7:2])
+                0: 
+                csr_rw[0] <= (csr_rw[0] & ~mem_wmask[7:0]) | (mem_wdata[7:0] & mem_wmask[7:0]);
+                1: 
+                csr_rw[1] <= (csr_rw[1] & ~mem_wmask[7:0]) | (mem_wdata[7:0] & mem_wmask[7:0]);
+                2: 
+                csr_rw[2] <= (csr_rw[2] & ~mem_wmask[7:0]) | (mem_wdata[7:0] & mem_wmask[7:0]);
+                3: 
+                csr_rw[3] <= (csr_rw[3] & ~mem_wmask[7:0]) | (mem_wdata[7:0] & mem_wmask[7:0]);
+                5: 
+                csr_tw_trig <= (csr_tw_trig & ~mem_wmask[0:0]) | (mem_wdata[0:0] & mem_wmask[0:0]);
+                default: 
+                ;
+              endcase
+            end
+        end
     end
-  end
-  always_comb begin
-    case (addr)
-      0: rdata[7:0] = csr_rw[0];
-      1: rdata[7:0] = csr_rw[1];
-      2: rdata[7:0] = csr_rw[2];
-      3: rdata[7:0] = csr_rw[3];
-      4: rdata[7:0] = csr_ro;
-      5: rdata[0:0] = csr_tw_resp;
-      default: rdata = 0;
-    endcase
-  end
-endmodule
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        mem_rdata <= 0;
+      else
+        begin
+          if (mem_req && !(|mem_wstrb))
+            begin
+              mem_rdata <= 0;
+              case (mem_addr[7:2])
+                0: 
+                mem_rdata[7:0] <= csr_rw[0];
+                1: 
+                mem_rdata[7:0] <= csr_rw[1];
+                2: 
+                mem_rdata[7:0] <= csr_rw[2];
+                3: 
+                mem_rdata[7:0] <= csr_rw[3];
+                4: 
+                mem_rdata[7:0] <= csr_ro;
+                5: 
+                mem_rdata[0:0] <= csr_tw_resp;
+                default: 
+                mem_rdata <= 0;
+              endcase
+            end
+        end
+    end
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        mem_ready <= 0;
+      else
+        mem_ready <= mem_req;
+    end
+endmodule
\ No newline at end of file
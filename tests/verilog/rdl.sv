@@ -0,0 +1,142 @@
+module rdl
+(
+  input  logic        clk,
+  input  logic        rstn,
+  input  logic [ 7:0] cbus_awaddr,
+  input  logic        cbus_awvalid,
+  output logic        cbus_awready,
+  input  logic [31:0] cbus_wdata,
+  input  logic [ 3:0] cbus_wstrb,
+  input  logic        cbus_wvalid,
+  output logic        cbus_wready,
+  output logic [ 1:0] cbus_bresp,
+  output logic        cbus_bvalid,
+  input  logic        cbus_bready,
+  input  logic [ 7:0] cbus_araddr,
+  input  logic        cbus_arvalid,
+  output logic        cbus_arready,
+  output logic [31:0] cbus_rdata,
+  output logic [ 1:0] cbus_rresp,
+  output logic        cbus_rvalid,
+  input  logic        cbus_rready,
+  output logic        cbus_irq
+)
+;
+  logic [15:0] ctrl;
+  logic [ 5:0] status;
+  logic        error;
+  logic        error_hw_we;
+  logic        error_hw_d;
+  logic [ 7:0] tx_data;
+  logic [15:0] filter_tap[ 3:0];
+  logic [ 1:0] int_status;
+  logic [ 1:0] int_enable;
+  always_comb
+    cbus_irq = |(int_status & int_enable);
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        begin
+          ctrl <= 6916;
+          error <= 0;
+          tx_data <= 0;
+          filter_tap[0] <= 0;
+          filter_tap[1] <= 0;
+          filter_tap[2] <= 0;
+          filter_tap[3] <= 0;
+          int_status <= 0;
+          int_enable <= 0;
+        end
+      else
+        begin
+          int_status <= int_status | {status[1], status[0]};
+          if (cbus_wvalid && cbus_awvalid)
+            begin
+              case (cbus_awaddr[7:2])
+                0: 
+                ctrl <= cbus_wdata[15:0];
+                2: 
+                error <= cbus_wdata[0:0];
+                4: 
+                tx_data <= cbus_wdata[7:0];
+                16: 
+                filter_tap[0] <= cbus_wdata[15:0];
+                18: 
+                filter_tap[1] <= cbus_wdata[15:0];
+                20: 
+                filter_tap[2] <= cbus_wdata[15:0];
+                22: 
+                filter_tap[3] <= cbus_wdata[15:0];
+                24: 
+                int_status <= (int_status & ~cbus_wdata[1:0]) | {status[1], status[0]};
+                25: 
+                int_enable <= cbus_wdata[1:0];
+                26: 
+                int_status <= int_status | cbus_wdata[1:0] | {status[1], status[0]};
+                default: 
+                ;
+              endcase
+            end
+          if (error_hw_we)
+            error <= error_hw_d;
+        end
+    end
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        cbus_rdata <= 0;
+      else
+        begin
+          if (cbus_arvalid)
+            begin
+              case (cbus_araddr[7:2])
+                0: 
+                cbus_rdata[15:0] <= ctrl;
+                1: 
+                cbus_rdata[5:0] <= status;
+                2: 
+                cbus_rdata[0:0] <= error;
+                16: 
+                cbus_rdata[15:0] <= filter_tap[0];
+                18: 
+                cbus_rdata[15:0] <= filter_tap[1];
+                20: 
+                cbus_rdata[15:0] <= filter_tap[2];
+                22: 
+                cbus_rdata[15:0] <= filter_tap[3];
+                24: 
+                cbus_rdata[1:0] <= int_status;
+                25: 
+                cbus_rdata[1:0] <= int_enable;
+                default: 
+                cbus_rdata <= 0;
+              endcase
+            end
+        end
+    end
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        begin
+          cbus_awready <= 0;
+          cbus_wready <= 0;
+          cbus_bvalid <= 0;
+          cbus_arready <= 0;
+          cbus_rvalid <= 0;
+          cbus_bresp <= 0;
+          cbus_rresp <= 0;
+        end
+      else
+        begin
+          cbus_awready <= cbus_awvalid && !cbus_awready;
+          cbus_wready <= cbus_wvalid && !cbus_wready;
+          cbus_bvalid <= cbus_awready && cbus_wready && !cbus_bvalid;
+          cbus_arready <= cbus_arvalid && !cbus_arready;
+          cbus_rvalid <= cbus_arvalid && !cbus_arready;
+          if (cbus_bvalid && cbus_bready)
+            cbus_bvalid <= 0;
+          if (cbus_rvalid && cbus_rready)
+            cbus_rvalid <= 0;
+        end
+    end
+endmodule
\ No newline at end of file
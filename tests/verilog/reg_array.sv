@@ -0,0 +1,125 @@
+module reg_array
+(
+  input  logic        clk,
+  input  logic        rstn,
+  input  logic [ 7:0] cbus_awaddr,
+  input  logic        cbus_awvalid,
+  output logic        cbus_awready,
+  input  logic [31:0] cbus_wdata,
+  input  logic [ 3:0] cbus_wstrb,
+  input  logic        cbus_wvalid,
+  output logic        cbus_wready,
+  output logic [ 1:0] cbus_bresp,
+  output logic        cbus_bvalid,
+  input  logic        cbus_bready,
+  input  logic [ 7:0] cbus_araddr,
+  input  logic        cbus_arvalid,
+  output logic        cbus_arready,
+  output logic [31:0] cbus_rdata,
+  output logic [ 1:0] cbus_rresp,
+  output logic        cbus_rvalid,
+  input  logic        cbus_rready
+)
+;
+  logic [ 7:0] ctrl;
+  logic        kick;
+  logic [15:0] lut[ 3:0];
+  logic [31:0] desc[ 1:0];
+  logic [ 7:0] status;
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        begin
+          ctrl <= 0;
+          kick <= 0;
+          lut[0] <= 0;
+          lut[1] <= 0;
+          lut[2] <= 0;
+          lut[3] <= 0;
+          desc[0] <= 0;
+          desc[1] <= 0;
+        end
+      else
+        begin
+          if (cbus_wvalid && cbus_awvalid)
+            begin
+              case (cbus_awaddr[7:2])
+                0: 
+                ctrl <= cbus_wdata[7:0];
+                1: 
+                kick <= cbus_wdata[0:0];
+                16: 
+                lut[0] <= cbus_wdata[15:0];
+                18: 
+                lut[1] <= cbus_wdata[15:0];
+                20: 
+                lut[2] <= cbus_wdata[15:0];
+                22: 
+                lut[3] <= cbus_wdata[15:0];
+                24: 
+                desc[0] <= cbus_wdata[31:0];
+                28: 
+                desc[1] <= cbus_wdata[31:0];
+                default: 
+                ;
+              endcase
+            end
+        end
+    end
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        cbus_rdata <= 0;
+      else
+        begin
+          if (cbus_arvalid)
+            begin
+              case (cbus_araddr[7:2])
+                0: 
+                cbus_rdata[7:0] <= ctrl;
+                16: 
+                cbus_rdata[15:0] <= lut[0];
+                18: 
+                cbus_rdata[15:0] <= lut[1];
+                20: 
+                cbus_rdata[15:0] <= lut[2];
+                22: 
+                cbus_rdata[15:0] <= lut[3];
+                24: 
+                cbus_rdata[31:0] <= desc[0];
+                28: 
+                cbus_rdata[31:0] <= desc[1];
+                32: 
+                cbus_rdata[7:0] <= status;
+                default: 
+                cbus_rdata <= 0;
+              endcase
+            end
+        end
+    end
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        begin
+          cbus_awready <= 0;
+          cbus_wready <= 0;
+          cbus_bvalid <= 0;
+          cbus_arready <= 0;
+          cbus_rvalid <= 0;
+          cbus_bresp <= 0;
+          cbus_rresp <= 0;
+        end
+      else
+        begin
+          cbus_awready <= cbus_awvalid && !cbus_awready;
+          cbus_wready <= cbus_wvalid && !cbus_wready;
+          cbus_bvalid <= cbus_awready && cbus_wready && !cbus_bvalid;
+          cbus_arready <= cbus_arvalid && !cbus_arready;
+          cbus_rvalid <= cbus_arvalid && !cbus_arready;
+          if (cbus_bvalid && cbus_bready)
+            cbus_bvalid <= 0;
+          if (cbus_rvalid && cbus_rready)
+            cbus_rvalid <= 0;
+        end
+    end
+endmodule
\ No newline at end of file
@@ -0,0 +1,22 @@
+module reset_sync
+(
+  input  logic        clk,
+  input  logic        arstn
+)
+;
+  (* ASYNC_REG = "TRUE" *) logic        rstn_s1;
+  (* ASYNC_REG = "TRUE" *) logic        rstn;
+  always_ff @(posedge clk or negedge arstn)
+    begin
+      if (!arstn)
+        begin
+          rstn_s1 <= 0;
+          rstn <= 0;
+        end
+      else
+        begin
+          rstn_s1 <= 1;
+          rstn <= rstn_s1;
+        end
+    end
+endmodule
\ No newline at end of file
@@ -0,0 +1,33 @@
+module state_machine_async_reset
+(
+  input  logic        clk,
+  input  logic        rst,
+  input  logic        go
+)
+;
+  logic        state;
+  localparam IDLE = 0;
+  localparam BUSY = 1;
+  always_ff @(posedge clk or posedge rst)
+    begin
+      if (rst)
+        state <= 0;
+      else
+        begin
+          case (state)
+            IDLE: 
+            begin
+              if (go)
+                state <= BUSY;
+              else
+                state <= IDLE;
+            end
+            BUSY: 
+            begin
+              else
+                state <= IDLE;
+            end
+          endcase
+        end
+    end
+endmodule
\ No newline at end of file
@@ -0,0 +1,26 @@
+module sync_bit
+(
+  input  logic        clk,
+  input  logic        rstn
+)
+;
+  logic        flag_in;
+  (* ASYNC_REG = "TRUE" *) logic        flag_s1;
+  (* ASYNC_REG = "TRUE" *) logic        flag_s2;
+  (* ASYNC_REG = "TRUE" *) logic        flag_out;
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        begin
+          flag_s1 <= 0;
+          flag_s2 <= 0;
+          flag_out <= 0;
+        end
+      else
+        begin
+          flag_s1 <= flag_in;
+          flag_s2 <= flag_s1;
+          flag_out <= flag_s2;
+        end
+    end
+endmodule
\ No newline at end of file
@@ -0,0 +1,86 @@
+module sync_handshake
+(
+  input  logic        clk_a,
+  input  logic        rstn_a,
+  input  logic        clk_b,
+  input  logic        rstn_b
+)
+;
+  logic        cfg_send;
+  logic [15:0] cfg_data;
+  logic        cfg_busy;
+  logic [15:0] cfg_hold;
+  logic        cfg_req;
+  logic [15:0] cfg_out;
+  logic        cfg_valid;
+  logic        cfg_ack;
+  (* ASYNC_REG = "TRUE" *) logic        cfg_ack_s1;
+  (* ASYNC_REG = "TRUE" *) logic        cfg_ack_s2;
+  always_ff @(posedge clk_a)
+    begin
+      if (!rstn_a)
+        begin
+          cfg_ack_s1 <= 0;
+          cfg_ack_s2 <= 0;
+        end
+      else
+        begin
+          cfg_ack_s1 <= cfg_ack;
+          cfg_ack_s2 <= cfg_ack_s1;
+        end
+    end
+  always_ff @(posedge clk_a)
+    begin
+      if (!rstn_a)
+        begin
+          cfg_busy <= 0;
+          cfg_hold <= 0;
+          cfg_req <= 0;
+        end
+      else
+        begin
+          if (cfg_send && !cfg_busy)
+            begin
+              cfg_hold <= cfg_data;
+              cfg_req <= !cfg_req;
+              cfg_busy <= 1;
+            end
+          else if (cfg_busy && cfg_ack_s2 == cfg_req)
+            cfg_busy <= 0;
+        end
+    end
+  (* ASYNC_REG = "TRUE" *) logic        cfg_req_s1;
+  (* ASYNC_REG = "TRUE" *) logic        cfg_req_s2;
+  always_ff @(posedge clk_b)
+    begin
+      if (!rstn_b)
+        begin
+          cfg_req_s1 <= 0;
+          cfg_req_s2 <= 0;
+        end
+      else
+        begin
+          cfg_req_s1 <= cfg_req;
+          cfg_req_s2 <= cfg_req_s1;
+        end
+    end
+  always_ff @(posedge clk_b)
+    begin
+      if (!rstn_b)
+        begin
+          cfg_out <= 0;
+          cfg_valid <= 0;
+          cfg_ack <= 0;
+        end
+      else
+        begin
+          cfg_valid <= 0;
+          if (cfg_req_s2 != cfg_ack)
+            begin
+              cfg_out <= cfg_hold;
+              cfg_valid <= 1;
+              cfg_ack <= cfg_req_s2;
+            end
+        end
+    end
+endmodule
\ No newline at end of file
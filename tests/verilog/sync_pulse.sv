@@ -0,0 +1,39 @@
+module sync_pulse
+(
+  input  logic        clk_a,
+  input  logic        rstn_a,
+  input  logic        clk_b,
+  input  logic        rstn_b
+)
+;
+  logic        kick_in;
+  logic        kick_out;
+  logic        kick_toggle;
+  always_ff @(posedge clk_a)
+    begin
+      if (!rstn_a)
+        kick_toggle <= 0;
+      else
+        kick_toggle <= kick_toggle ^ kick_in;
+    end
+  (* ASYNC_REG = "TRUE" *) logic        kick_s1;
+  (* ASYNC_REG = "TRUE" *) logic        kick_s2;
+  (* ASYNC_REG = "TRUE" *) logic        kick_s3;
+  always_ff @(posedge clk_b)
+    begin
+      if (!rstn_b)
+        begin
+          kick_s1 <= 0;
+          kick_s2 <= 0;
+          kick_s3 <= 0;
+        end
+      else
+        begin
+          kick_s1 <= kick_toggle;
+          kick_s2 <= kick_s1;
+          kick_s3 <= kick_s2;
+        end
+    end
+  always_comb
+    kick_out = kick_s2 ^ kick_s3;
+endmodule
\ No newline at end of file
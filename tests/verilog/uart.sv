@@ -37,7 +37,7 @@ module uart
         begin
           if (cbus_wvalid && cbus_awvalid)
             begin
-              case (cbus_awaddr)
+              case (cbus_awaddr[7:2])
                 0: 
                 div <= cbus_wdata[31:0];
                 1: 
@@ -56,7 +56,7 @@ module uart
         begin
           if (cbus_arvalid)
             begin
-              case (cbus_araddr)
+              case (cbus_araddr[7:2])
                 0: 
                 cbus_rdata[31:0] <= div;
                 1: 
@@ -0,0 +1,91 @@
+module wishbone_interconnect
+(
+  input  logic        clk,
+  input  logic        rstn,
+  input  logic        cpu_cyc,
+  input  logic        cpu_stb,
+  input  logic        cpu_we,
+  input  logic [ 3:0] cpu_sel,
+  input  logic [11:0] cpu_adr,
+  input  logic [31:0] cpu_dat_w,
+  output logic [31:0] cpu_dat_r,
+  output logic        cpu_ack,
+  output logic        cpu_err,
+  output logic        uart_cyc,
+  output logic        uart_stb,
+  output logic        uart_we,
+  output logic [ 3:0] uart_sel,
+  output logic [ 3:0] uart_adr,
+  output logic [31:0] uart_dat_w,
+  input  logic [31:0] uart_dat_r,
+  input  logic        uart_ack,
+  input  logic        uart_err,
+  output logic        gpio_cyc,
+  output logic        gpio_stb,
+  output logic        gpio_we,
+  output logic [ 3:0] gpio_sel,
+  output logic [ 7:0] gpio_adr,
+  output logic [31:0] gpio_dat_w,
+  input  logic [31:0] gpio_dat_r,
+  input  logic        gpio_ack,
+  input  logic        gpio_err
+)
+;
+  logic [ 1:0] cpu_dec;
+  always_comb
+    begin
+      if (cpu_adr[11:4] == 1)
+        cpu_dec = 0;
+      else if (cpu_adr[11:8] == 1)
+        cpu_dec = 1;
+      else
+        cpu_dec = 2;
+    end
+  always_comb
+    begin
+      uart_cyc = cpu_cyc && cpu_dec == 0;
+      uart_stb = cpu_stb && cpu_dec == 0;
+      uart_we = cpu_we;
+      uart_sel = cpu_sel;
+      uart_adr = cpu_adr[3:0];
+      uart_dat_w = cpu_dat_w;
+    end
+  always_comb
+    begin
+      gpio_cyc = cpu_cyc && cpu_dec == 1;
+      gpio_stb = cpu_stb && cpu_dec == 1;
+      gpio_we = cpu_we;
+      gpio_sel = cpu_sel;
+      gpio_adr = cpu_adr[7:0];
+      gpio_dat_w = cpu_dat_w;
+    end
+  logic        cpu_herr;
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        cpu_herr <= 0;
+      else
+        cpu_herr <= cpu_cyc && cpu_stb && cpu_dec == 2 && !cpu_herr;
+    end
+  always_comb
+    case (cpu_dec)
+      0: 
+      begin
+        cpu_dat_r = uart_dat_r;
+        cpu_ack = uart_ack;
+        cpu_err = uart_err;
+      end
+      1: 
+      begin
+        cpu_dat_r = gpio_dat_r;
+        cpu_ack = gpio_ack;
+        cpu_err = gpio_err;
+      end
+      default: 
+      begin
+        cpu_dat_r = 0;
+        cpu_ack = 0;
+        cpu_err = cpu_herr;
+      end
+    endcase
+endmodule
\ No newline at end of file
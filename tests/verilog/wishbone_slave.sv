@@ -0,0 +1,110 @@
+module wishbone_slave
+(
+  input  logic        clk,
+  input  logic        rstn,
+  input  logic        wb_cyc,
+  input  logic        wb_stb,
+  input  logic        wb_we,
+  input  logic [ 3:0] wb_sel,
+  input  logic [ 7:0] wb_adr,
+  input  logic [31:0] wb_dat_w,
+  output logic [31:0] wb_dat_r,
+  output logic        wb_ack,
+  output logic        wb_err,
+  output logic        wb_stall
+)
+;
+  logic [ 7:0] csr_rw[ 3:0];
+  logic [ 7:0] csr_ro;
+  logic        csr_tw_trig;
+  logic        csr_tw_resp;
+  logic        wb_req;
+  logic        wb_miss;
+  always_comb
+    wb_stall = 0;
+  always_comb
+    begin
+      wb_req = wb_cyc && wb_stb && !wb_stall;
+      case (wb_adr[7:2])
+        0, 1, 2, 3, 4, 5: 
+        wb_miss = 0;
+        default: 
+        wb_miss = 1;
+      endcase
+    end
+  logic [31:0] wb_wmask;
+  always_comb
+    wb_wmask = {{8{wb_sel[3]}}, {8{wb_sel[2]}}, {8{wb_sel[1]}}, {8{wb_sel[0]}}};
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        begin
+          csr_rw[0] <= 0;
+          csr_rw[1] <= 0;
+          csr_rw[2] <= 0;
+          csr_rw[3] <= 0;
+          csr_tw_trig <= 0;
+        end
+      else
+        begin
+          if (wb_req && wb_we)
+            begin
+              case (wb_adr[7:2])
+                0: 
+                csr_rw[0] <= (csr_rw[0] & ~wb_wmask[7:0]) | (wb_dat_w[7:0] & wb_wmask[7:0]);
+                1: 
+                csr_rw[1] <= (csr_rw[1] & ~wb_wmask[7:0]) | (wb_dat_w[7:0] & wb_wmask[7:0]);
+                2: 
+                csr_rw[2] <= (csr_rw[2] & ~wb_wmask[7:0]) | (wb_dat_w[7:0] & wb_wmask[7:0]);
+                3: 
+                csr_rw[3] <= (csr_rw[3] & ~wb_wmask[7:0]) | (wb_dat_w[7:0] & wb_wmask[7:0]);
+                5: 
+                csr_tw_trig <= (csr_tw_trig & ~wb_wmask[0:0]) | (wb_dat_w[0:0] & wb_wmask[0:0]);
+                default: 
+                ;
+              endcase
+            end
+        end
+    end
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        wb_dat_r <= 0;
+      else
+        begin
+          if (wb_req && !wb_we)
+            begin
+              wb_dat_r <= 0;
+              case (wb_adr[7:2])
+                0: 
+                wb_dat_r[7:0] <= csr_rw[0];
+                1: 
+                wb_dat_r[7:0] <= csr_rw[1];
+                2: 
+                wb_dat_r[7:0] <= csr_rw[2];
+                3: 
+                wb_dat_r[7:0] <= csr_rw[3];
+                4: 
+                wb_dat_r[7:0] <= csr_ro;
+                5: 
+                wb_dat_r[0:0] <= csr_tw_resp;
+                default: 
+                wb_dat_r <= 0;
+              endcase
+            end
+        end
+    end
+  always_ff @(posedge clk)
+    begin
+      if (!rstn)
+        begin
+          wb_ack <= 0;
+          wb_err <= 0;
+        end
+      else
+        begin
+          wb_ack <= wb_req && !wb_miss;
+          wb_err <= wb_req && wb_miss;
+        end
+    end
+endmodule
\ No newline at end of file
@@ -0,0 +1,25 @@
+use ruverta::{
+    bus::{RegList, WishboneSlave},
+    mod_test,
+    module::Module,
+};
+
+mod_test!(
+    wishbone_slave,
+    Module::new("wishbone_slave", "clk", "rstn")
+        .input("clk", 1)
+        .input("rstn", 1)
+        .add(
+            WishboneSlave::new(
+                Some("wb"),
+                "clk",
+                "rstn",
+                RegList::new()
+                    .read_write("csr_rw", 8, 4)
+                    .read_only("csr_ro", 8, 1)
+                    .trigger("csr_tw")
+                    .allocate_greedy(32, 8),
+            )
+            .pipelined()
+        )
+);